@@ -0,0 +1,2 @@
+ALTER TABLE sessions ADD COLUMN country TEXT;
+ALTER TABLE sessions ADD COLUMN language TEXT;
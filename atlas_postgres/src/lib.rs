@@ -0,0 +1,35 @@
+#![forbid(unsafe_code)]
+#![deny(unused_must_use)]
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::panic)]
+#![deny(clippy::perf)]
+#![deny(clippy::nursery)]
+#![allow(clippy::module_name_repetitions)]
+
+mod sessions;
+mod user;
+
+pub use sessions::PostgresSessionDB;
+pub use sqlx::postgres::PgPool;
+pub use user::PostgresUserDB;
+
+use atlasserver::error::{Error, Result};
+
+static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!();
+
+/// applies the bundled schema migrations, safe to call repeatedly
+///
+/// # Errors
+///
+/// fails if the database is unreachable or a migration fails
+pub async fn migrate(pool: &PgPool) -> Result<()> {
+	MIGRATOR.run(pool).await.map_err(|e| {
+		Error::Custom(format!("postgres migration error: {e}"))
+	})
+}
+
+fn db_error(e: &sqlx::Error) -> Error {
+	Error::Custom(format!("postgres error: {e}"))
+}
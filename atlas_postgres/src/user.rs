@@ -0,0 +1,119 @@
+use crate::{db_error, migrate, PgPool};
+use async_trait::async_trait;
+use atlasserver::{
+	error::{Error, Result},
+	userlogin::user::{User, UserDB},
+};
+use sqlx::Row;
+use std::convert::TryFrom;
+
+#[derive(Clone)]
+pub struct PostgresUserDB {
+	pool: PgPool,
+}
+
+impl PostgresUserDB {
+	/// create new `PostgresUserDB` instance reusing an existing connection pool
+	///
+	/// # Errors
+	///
+	/// applying the schema migrations could fail
+	pub async fn new(pool: PgPool) -> Result<Self> {
+		migrate(&pool).await?;
+		Ok(Self { pool })
+	}
+}
+
+#[async_trait]
+impl UserDB for PostgresUserDB {
+	async fn get_user(&self, key: &str) -> Option<User> {
+		let row = sqlx::query(
+			"SELECT id, secret, version, session, country, language, \
+			 deleted_at FROM users WHERE id = $1",
+		)
+		.bind(key)
+		.fetch_optional(&self.pool)
+		.await
+		.map_err(|e| tracing::error!("get_user error: {}", e))
+		.ok()??;
+
+		Some(User {
+			id: row.try_get("id").ok()?,
+			secret: row.try_get("secret").ok()?,
+			version: u64::try_from(
+				row.try_get::<i64, _>("version").ok()?,
+			)
+			.ok()?,
+			session: row.try_get("session").ok()?,
+			country: row.try_get("country").ok()?,
+			language: row.try_get("language").ok()?,
+			deleted_at: row.try_get("deleted_at").ok()?,
+		})
+	}
+
+	/// mirrors the optimistic locking of `DynamoUserDB`: a user with `version > 0`
+	/// is only written if the stored version is exactly one below
+	async fn save_user(&self, u: &User) -> Result<()> {
+		let version = i64::try_from(u.version)
+			.map_err(|e| Error::Custom(e.to_string()))?;
+
+		let res = sqlx::query(
+			"INSERT INTO users \
+			 (id, secret, version, session, country, language, deleted_at) \
+			 VALUES ($1, $2, $3, $4, $5, $6, $7) \
+			 ON CONFLICT (id) DO UPDATE SET \
+			 secret = $2, version = $3, session = $4, country = $5, language = $6, \
+			 deleted_at = $7 \
+			 WHERE $3 = 0 OR users.version = $3 - 1",
+		)
+		.bind(&u.id)
+		.bind(&u.secret)
+		.bind(version)
+		.bind(&u.session)
+		.bind(&u.country)
+		.bind(&u.language)
+		.bind(u.deleted_at)
+		.execute(&self.pool)
+		.await
+		.map_err(|e| db_error(&e))?;
+
+		if res.rows_affected() == 0 {
+			return Err(Error::Custom(format!(
+				"user version mismatch: {}",
+				u.id
+			)));
+		}
+
+		Ok(())
+	}
+
+	async fn delete_user(
+		&self,
+		key: &str,
+		timestamp: i64,
+	) -> Result<bool> {
+		let res = sqlx::query(
+			"DELETE FROM users WHERE id = $1 AND deleted_at < $2",
+		)
+		.bind(key)
+		.bind(timestamp)
+		.execute(&self.pool)
+		.await
+		.map_err(|e| db_error(&e))?;
+
+		Ok(res.rows_affected() > 0)
+	}
+
+	async fn list_deleted_before(
+		&self,
+		timestamp: i64,
+	) -> Result<Vec<String>> {
+		sqlx::query_scalar(
+			"SELECT id FROM users WHERE deleted_at < $1",
+		)
+		.bind(timestamp)
+		.fetch_all(&self.pool)
+		.await
+		.map_err(|e| db_error(&e))
+	}
+}
@@ -0,0 +1,151 @@
+//! these tests need a running postgres instance at `DATABASE_URL`,
+//! run them via `cargo test -p atlas_postgres -- --ignored`
+
+use atlas_postgres::{PgPool, PostgresSessionDB, PostgresUserDB};
+use atlasserver::userlogin::{
+	sessions::{Session, SessionDB},
+	user::{User, UserDB},
+};
+use chrono::{DateTime, Duration, Utc};
+
+async fn pool() -> PgPool {
+	let url = std::env::var("DATABASE_URL")
+		.expect("DATABASE_URL needs to be set");
+	PgPool::connect(&url).await.unwrap()
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_user_roundtrip() {
+	let pool = pool().await;
+	let db = PostgresUserDB::new(pool).await.unwrap();
+
+	let mut user = User::new(Some("DE".into()), None);
+	db.save_user(&user).await.unwrap();
+
+	assert_eq!(db.get_user(&user.id).await.unwrap(), user);
+
+	user.version += 1;
+	db.save_user(&user).await.unwrap();
+
+	// stale version must be rejected
+	assert!(db.save_user(&user).await.is_err());
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_user_soft_delete() {
+	let pool = pool().await;
+	let db = PostgresUserDB::new(pool).await.unwrap();
+
+	let mut user = User::new(None, None);
+	db.save_user(&user).await.unwrap();
+
+	user.version += 1;
+	user.deleted_at = Some(100);
+	db.save_user(&user).await.unwrap();
+
+	assert_eq!(db.get_user(&user.id).await.unwrap(), user);
+	assert!(db
+		.list_deleted_before(101)
+		.await
+		.unwrap()
+		.contains(&user.id));
+	assert!(!db
+		.list_deleted_before(100)
+		.await
+		.unwrap()
+		.contains(&user.id));
+
+	// deleted too recently
+	assert!(!db.delete_user(&user.id, 100).await.unwrap());
+	assert!(db.delete_user(&user.id, 101).await.unwrap());
+	assert!(!db.delete_user(&user.id, 101).await.unwrap());
+
+	assert!(db.get_user(&user.id).await.is_none());
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_session_invalidate() {
+	let pool = pool().await;
+	let db = PostgresSessionDB::new(pool).await.unwrap();
+
+	let key = db.create(Session::new("uid")).await.unwrap();
+	assert!(db.get(&key).await.unwrap().valid);
+
+	assert!(db.invalidate(&key).await.is_some());
+	assert!(!db.get(&key).await.unwrap().valid);
+
+	assert!(db.invalidate("unknown").await.is_none());
+	assert!(db.get("unknown").await.is_none());
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_session_peek() {
+	let pool = pool().await;
+	let db = PostgresSessionDB::new(pool.clone()).await.unwrap();
+
+	let key = db.create(Session::new("uid")).await.unwrap();
+	let expires_at = Utc::now() + Duration::days(365);
+	sqlx::query("UPDATE sessions SET expires_at = $2 WHERE id = $1")
+		.bind(&key)
+		.bind(expires_at)
+		.execute(&pool)
+		.await
+		.unwrap();
+
+	assert_eq!(db.peek(&key).await.unwrap().user_id, "uid");
+	assert!(db.peek("unknown").await.is_none());
+
+	// peeking must not refresh the timeout
+	let (stored,): (DateTime<Utc>,) = sqlx::query_as(
+		"SELECT expires_at FROM sessions WHERE id = $1",
+	)
+	.bind(&key)
+	.fetch_one(&pool)
+	.await
+	.unwrap();
+	assert_eq!(stored.timestamp(), expires_at.timestamp());
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_session_revoke_all() {
+	let pool = pool().await;
+	let db = PostgresSessionDB::new(pool).await.unwrap();
+
+	let user_id = uuid::Uuid::new_v4().to_string();
+	let s1 = db.create(Session::new(&user_id)).await.unwrap();
+	let s2 = db.create(Session::new(&user_id)).await.unwrap();
+	let other = db.create(Session::new("other")).await.unwrap();
+	db.invalidate(&s2).await.unwrap();
+
+	let mut sessions = db.list_for_user(&user_id).await.unwrap();
+	sessions.sort_by_key(|(key, _)| key != &s1);
+	assert_eq!(sessions.len(), 2);
+	assert!(sessions[0].1.valid);
+	assert!(!sessions[1].1.valid);
+
+	assert_eq!(
+		db.invalidate_all_for_user(&user_id).await.unwrap(),
+		1
+	);
+	assert!(!db.get(&s1).await.unwrap().valid);
+	assert!(db.get(&other).await.unwrap().valid);
+}
+
+#[tokio::test]
+#[ignore = "needs DATABASE_URL"]
+async fn test_session_device() {
+	let pool = pool().await;
+	let db = PostgresSessionDB::new(pool).await.unwrap();
+
+	let session = Session::new("uid")
+		.with_device(Some("device".into()), Some("ios".into()))
+		.with_locale(Some("DE".into()), Some("de".into()));
+	let key = db.create(session.clone()).await.unwrap();
+
+	assert_eq!(db.get(&key).await.unwrap(), session);
+}
@@ -0,0 +1,2 @@
+ALTER TABLE sessions ADD COLUMN device_id TEXT;
+ALTER TABLE sessions ADD COLUMN platform TEXT;
@@ -0,0 +1,55 @@
+#![forbid(unsafe_code)]
+#![deny(unused_must_use)]
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::panic)]
+#![deny(clippy::perf)]
+#![deny(clippy::nursery)]
+#![allow(clippy::module_name_repetitions)]
+
+mod sessions;
+mod user;
+
+pub use sessions::SqliteSessionDB;
+pub use sqlx::sqlite::SqlitePool;
+pub use user::SqliteUserDB;
+
+use atlasserver::error::{Error, Result};
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode};
+use std::path::Path;
+
+static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!();
+
+/// opens (or creates) the database file at `path` in WAL mode
+///
+/// # Errors
+///
+/// fails if the file cannot be opened or created
+pub async fn connect(
+	path: impl AsRef<Path> + Send,
+) -> Result<SqlitePool> {
+	let options = SqliteConnectOptions::new()
+		.filename(path)
+		.create_if_missing(true)
+		.journal_mode(SqliteJournalMode::Wal);
+
+	SqlitePool::connect_with(options)
+		.await
+		.map_err(|e| db_error(&e))
+}
+
+/// applies the bundled schema migrations, safe to call repeatedly
+///
+/// # Errors
+///
+/// fails if a migration fails
+pub async fn migrate(pool: &SqlitePool) -> Result<()> {
+	MIGRATOR.run(pool).await.map_err(|e| {
+		Error::Custom(format!("sqlite migration error: {e}"))
+	})
+}
+
+fn db_error(e: &sqlx::Error) -> Error {
+	Error::Custom(format!("sqlite error: {e}"))
+}
@@ -0,0 +1,209 @@
+use crate::{db_error, migrate, SqlitePool};
+use async_trait::async_trait;
+use atlasserver::{
+	error::Result,
+	userlogin::sessions::{Session, SessionDB},
+};
+use chrono::{DateTime, Duration, Utc};
+use sqlx::Row;
+use tracing::instrument;
+
+#[derive(Clone)]
+pub struct SqliteSessionDB {
+	pool: SqlitePool,
+}
+
+impl SqliteSessionDB {
+	/// create new `SqliteSessionDB` instance reusing an existing connection pool,
+	/// see [`crate::connect`]
+	///
+	/// # Errors
+	///
+	/// applying the schema migrations could fail
+	pub async fn new(pool: SqlitePool) -> Result<Self> {
+		migrate(&pool).await?;
+		Ok(Self { pool })
+	}
+
+	#[must_use]
+	pub fn ttl(now: DateTime<Utc>) -> i64 {
+		let now: DateTime<Utc> = now + Duration::minutes(5);
+		now.timestamp()
+	}
+
+	/// deletes all timed out sessions, sqlite has no native row expiry
+	/// so this should be called periodically
+	///
+	/// # Errors
+	///
+	/// fails with database errors
+	pub async fn delete_expired(&self) -> Result<u64> {
+		let res =
+			sqlx::query("DELETE FROM sessions WHERE expires_at < ?")
+				.bind(Utc::now().timestamp())
+				.execute(&self.pool)
+				.await
+				.map_err(|e| db_error(&e))?;
+
+		Ok(res.rows_affected())
+	}
+}
+
+#[async_trait]
+impl SessionDB for SqliteSessionDB {
+	#[instrument(skip(self), err)]
+	async fn create(&self, session: Session) -> Result<String> {
+		tracing::trace!("SessionDB::create");
+
+		let key = Session::new_key();
+
+		sqlx::query(
+			"INSERT INTO sessions \
+			 (id, user_id, valid, expires_at, device_id, platform, \
+			 country, language) \
+			 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+		)
+		.bind(&key)
+		.bind(&session.user_id)
+		.bind(session.valid)
+		.bind(Self::ttl(Utc::now()))
+		.bind(&session.device_id)
+		.bind(&session.platform)
+		.bind(&session.country)
+		.bind(&session.language)
+		.execute(&self.pool)
+		.await
+		.map_err(|e| db_error(&e))?;
+
+		Ok(key)
+	}
+
+	#[instrument(skip(self))]
+	async fn invalidate(&self, key: &str) -> Option<()> {
+		tracing::debug!("SessionDB::invalidate");
+
+		let res =
+			sqlx::query("UPDATE sessions SET valid = 0 WHERE id = ?")
+				.bind(key)
+				.execute(&self.pool)
+				.await
+				.map_err(|e| {
+					tracing::error!(
+						"error invalidating session: {}",
+						e
+					);
+				})
+				.ok()?;
+
+		(res.rows_affected() > 0).then_some(())
+	}
+
+	#[instrument(skip(self))]
+	async fn get(&self, key: &str) -> Option<Session> {
+		tracing::trace!("SessionDB::get");
+
+		let now = Utc::now();
+
+		let row = sqlx::query(
+			"UPDATE sessions SET expires_at = ? \
+			 WHERE id = ? AND expires_at >= ? \
+			 RETURNING user_id, valid, device_id, platform, country, language",
+		)
+		.bind(Self::ttl(now))
+		.bind(key)
+		.bind(now.timestamp())
+		.fetch_optional(&self.pool)
+		.await
+		.map_err(|e| tracing::error!("update error: {}", e))
+		.ok()??;
+
+		Some(Session {
+			user_id: row.try_get("user_id").ok()?,
+			valid: row.try_get("valid").ok()?,
+			device_id: row.try_get("device_id").ok()?,
+			platform: row.try_get("platform").ok()?,
+			country: row.try_get("country").ok()?,
+			language: row.try_get("language").ok()?,
+		})
+	}
+
+	#[instrument(skip(self))]
+	async fn peek(&self, key: &str) -> Option<Session> {
+		tracing::trace!("SessionDB::peek");
+
+		let row = sqlx::query(
+			"SELECT user_id, valid, device_id, platform, country, language \
+			 FROM sessions WHERE id = ? AND expires_at >= ?",
+		)
+		.bind(key)
+		.bind(Utc::now().timestamp())
+		.fetch_optional(&self.pool)
+		.await
+		.map_err(|e| tracing::error!("select error: {e}"))
+		.ok()??;
+
+		Some(Session {
+			user_id: row.try_get("user_id").ok()?,
+			valid: row.try_get("valid").ok()?,
+			device_id: row.try_get("device_id").ok()?,
+			platform: row.try_get("platform").ok()?,
+			country: row.try_get("country").ok()?,
+			language: row.try_get("language").ok()?,
+		})
+	}
+
+	#[instrument(skip(self), err)]
+	async fn list_for_user(
+		&self,
+		user_id: &str,
+	) -> Result<Vec<(String, Session)>> {
+		tracing::trace!("SessionDB::list_for_user");
+
+		let rows = sqlx::query(
+			"SELECT id, user_id, valid, device_id, platform, country, language \
+			 FROM sessions \
+			 WHERE user_id = ? AND expires_at >= ?",
+		)
+		.bind(user_id)
+		.bind(Utc::now().timestamp())
+		.fetch_all(&self.pool)
+		.await
+		.map_err(|e| db_error(&e))?;
+
+		rows.iter()
+			.map(|row| {
+				Ok((
+					row.try_get("id")?,
+					Session {
+						user_id: row.try_get("user_id")?,
+						valid: row.try_get("valid")?,
+						device_id: row.try_get("device_id")?,
+						platform: row.try_get("platform")?,
+						country: row.try_get("country")?,
+						language: row.try_get("language")?,
+					},
+				))
+			})
+			.collect::<std::result::Result<_, sqlx::Error>>()
+			.map_err(|e| db_error(&e))
+	}
+
+	#[instrument(skip(self), err)]
+	async fn invalidate_all_for_user(
+		&self,
+		user_id: &str,
+	) -> Result<usize> {
+		tracing::debug!("SessionDB::invalidate_all_for_user");
+
+		let res = sqlx::query(
+			"UPDATE sessions SET valid = 0 \
+			 WHERE user_id = ? AND valid",
+		)
+		.bind(user_id)
+		.execute(&self.pool)
+		.await
+		.map_err(|e| db_error(&e))?;
+
+		Ok(usize::try_from(res.rows_affected()).unwrap_or(usize::MAX))
+	}
+}
@@ -0,0 +1,122 @@
+use crate::{db_error, migrate, SqlitePool};
+use async_trait::async_trait;
+use atlasserver::{
+	error::{Error, Result},
+	userlogin::user::{User, UserDB},
+};
+use sqlx::Row;
+use std::convert::TryFrom;
+
+#[derive(Clone)]
+pub struct SqliteUserDB {
+	pool: SqlitePool,
+}
+
+impl SqliteUserDB {
+	/// create new `SqliteUserDB` instance reusing an existing connection pool,
+	/// see [`crate::connect`]
+	///
+	/// # Errors
+	///
+	/// applying the schema migrations could fail
+	pub async fn new(pool: SqlitePool) -> Result<Self> {
+		migrate(&pool).await?;
+		Ok(Self { pool })
+	}
+}
+
+#[async_trait]
+impl UserDB for SqliteUserDB {
+	async fn get_user(&self, key: &str) -> Option<User> {
+		let row = sqlx::query(
+			"SELECT id, secret, version, session, country, language, \
+			 deleted_at FROM users WHERE id = ?",
+		)
+		.bind(key)
+		.fetch_optional(&self.pool)
+		.await
+		.map_err(|e| tracing::error!("get_user error: {}", e))
+		.ok()??;
+
+		Some(User {
+			id: row.try_get("id").ok()?,
+			secret: row.try_get("secret").ok()?,
+			version: u64::try_from(
+				row.try_get::<i64, _>("version").ok()?,
+			)
+			.ok()?,
+			session: row.try_get("session").ok()?,
+			country: row.try_get("country").ok()?,
+			language: row.try_get("language").ok()?,
+			deleted_at: row.try_get("deleted_at").ok()?,
+		})
+	}
+
+	/// mirrors the optimistic locking of `DynamoUserDB`: a user with `version > 0`
+	/// is only written if the stored version is exactly one below
+	async fn save_user(&self, u: &User) -> Result<()> {
+		let version = i64::try_from(u.version)
+			.map_err(|e| Error::Custom(e.to_string()))?;
+
+		let res = sqlx::query(
+			"INSERT INTO users \
+			 (id, secret, version, session, country, language, deleted_at) \
+			 VALUES (?, ?, ?, ?, ?, ?, ?) \
+			 ON CONFLICT (id) DO UPDATE SET \
+			 secret = excluded.secret, version = excluded.version, \
+			 session = excluded.session, country = excluded.country, \
+			 language = excluded.language, deleted_at = excluded.deleted_at \
+			 WHERE excluded.version = 0 \
+			 OR users.version = excluded.version - 1",
+		)
+		.bind(&u.id)
+		.bind(&u.secret)
+		.bind(version)
+		.bind(&u.session)
+		.bind(&u.country)
+		.bind(&u.language)
+		.bind(u.deleted_at)
+		.execute(&self.pool)
+		.await
+		.map_err(|e| db_error(&e))?;
+
+		if res.rows_affected() == 0 {
+			return Err(Error::Custom(format!(
+				"user version mismatch: {}",
+				u.id
+			)));
+		}
+
+		Ok(())
+	}
+
+	async fn delete_user(
+		&self,
+		key: &str,
+		timestamp: i64,
+	) -> Result<bool> {
+		let res = sqlx::query(
+			"DELETE FROM users WHERE id = ? AND deleted_at < ?",
+		)
+		.bind(key)
+		.bind(timestamp)
+		.execute(&self.pool)
+		.await
+		.map_err(|e| db_error(&e))?;
+
+		Ok(res.rows_affected() > 0)
+	}
+
+	async fn list_deleted_before(
+		&self,
+		timestamp: i64,
+	) -> Result<Vec<String>> {
+		sqlx::query_scalar(
+			"SELECT id FROM users WHERE deleted_at < ?",
+		)
+		.bind(timestamp)
+		.fetch_all(&self.pool)
+		.await
+		.map_err(|e| db_error(&e))
+	}
+}
@@ -0,0 +1,215 @@
+use atlas_sqlite::{
+	connect, SqlitePool, SqliteSessionDB, SqliteUserDB,
+};
+use atlasserver::userlogin::{
+	sessions::{Session, SessionDB},
+	user::{User, UserDB},
+};
+use std::path::PathBuf;
+
+/// database file in the temp dir, removed (incl. wal/shm) on drop
+struct TempDb(PathBuf);
+
+impl TempDb {
+	fn new() -> Self {
+		Self(
+			std::env::temp_dir().join(format!(
+				"atlas-{}.sqlite",
+				uuid::Uuid::new_v4()
+			)),
+		)
+	}
+
+	async fn pool(&self) -> SqlitePool {
+		connect(&self.0).await.unwrap()
+	}
+}
+
+impl Drop for TempDb {
+	fn drop(&mut self) {
+		for suffix in ["", "-wal", "-shm"] {
+			let mut path = self.0.clone().into_os_string();
+			path.push(suffix);
+			let _ = std::fs::remove_file(path);
+		}
+	}
+}
+
+#[tokio::test]
+async fn test_user_roundtrip() {
+	let tmp = TempDb::new();
+	let db = SqliteUserDB::new(tmp.pool().await).await.unwrap();
+
+	let mut user = User::new(Some("DE".into()), None);
+	db.save_user(&user).await.unwrap();
+
+	assert_eq!(db.get_user(&user.id).await.unwrap(), user);
+	assert!(db.get_user("unknown").await.is_none());
+
+	user.version += 1;
+	user.language = Some("en-CA".into());
+	db.save_user(&user).await.unwrap();
+
+	assert_eq!(db.get_user(&user.id).await.unwrap(), user);
+
+	// stale version must be rejected
+	assert!(db.save_user(&user).await.is_err());
+}
+
+#[tokio::test]
+async fn test_user_soft_delete() {
+	let tmp = TempDb::new();
+	let db = SqliteUserDB::new(tmp.pool().await).await.unwrap();
+
+	let mut user = User::new(None, None);
+	db.save_user(&user).await.unwrap();
+	let other = User::new(None, None);
+	db.save_user(&other).await.unwrap();
+
+	user.version += 1;
+	user.deleted_at = Some(100);
+	db.save_user(&user).await.unwrap();
+
+	assert_eq!(db.get_user(&user.id).await.unwrap(), user);
+	assert_eq!(
+		db.list_deleted_before(101).await.unwrap(),
+		vec![user.id.clone()]
+	);
+	assert!(db.list_deleted_before(100).await.unwrap().is_empty());
+
+	// only users soft deleted before the cutoff are removed
+	assert!(!db.delete_user(&other.id, 101).await.unwrap());
+	assert!(!db.delete_user(&user.id, 100).await.unwrap());
+	assert!(db.delete_user(&user.id, 101).await.unwrap());
+
+	assert!(db.get_user(&user.id).await.is_none());
+	assert!(db.get_user(&other.id).await.is_some());
+}
+
+#[tokio::test]
+async fn test_user_persists() {
+	let tmp = TempDb::new();
+	let user = User::new(None, None);
+
+	{
+		let db = SqliteUserDB::new(tmp.pool().await).await.unwrap();
+		db.save_user(&user).await.unwrap();
+	}
+
+	let db = SqliteUserDB::new(tmp.pool().await).await.unwrap();
+	assert_eq!(db.get_user(&user.id).await.unwrap(), user);
+}
+
+#[tokio::test]
+async fn test_session_invalidate() {
+	let tmp = TempDb::new();
+	let db = SqliteSessionDB::new(tmp.pool().await).await.unwrap();
+
+	let key = db.create(Session::new("uid")).await.unwrap();
+	let session = db.get(&key).await.unwrap();
+	assert!(session.valid);
+	assert_eq!(session.user_id, "uid");
+
+	assert!(db.invalidate(&key).await.is_some());
+	assert!(!db.get(&key).await.unwrap().valid);
+
+	assert!(db.invalidate("unknown").await.is_none());
+	assert!(db.get("unknown").await.is_none());
+}
+
+#[tokio::test]
+async fn test_session_expired() {
+	let tmp = TempDb::new();
+	let pool = tmp.pool().await;
+	let db = SqliteSessionDB::new(pool.clone()).await.unwrap();
+
+	let key = db.create(Session::new("uid")).await.unwrap();
+	assert_eq!(db.delete_expired().await.unwrap(), 0);
+	assert!(db.get(&key).await.is_some());
+
+	sqlx::query(
+		"INSERT INTO sessions (id, user_id, valid, expires_at) \
+		 VALUES ('expired', 'uid', TRUE, 1)",
+	)
+	.execute(&pool)
+	.await
+	.unwrap();
+
+	assert!(db.get("expired").await.is_none());
+	assert_eq!(db.delete_expired().await.unwrap(), 1);
+	assert!(db.get(&key).await.is_some());
+
+	let count: (i64,) = sqlx::query_as(
+		"SELECT COUNT(*) FROM sessions WHERE id = 'expired'",
+	)
+	.fetch_one(&pool)
+	.await
+	.unwrap();
+	assert_eq!(count.0, 0);
+}
+
+#[tokio::test]
+async fn test_session_peek() {
+	let tmp = TempDb::new();
+	let pool = tmp.pool().await;
+	let db = SqliteSessionDB::new(pool.clone()).await.unwrap();
+
+	let key = db.create(Session::new("uid")).await.unwrap();
+	sqlx::query("UPDATE sessions SET expires_at = ? WHERE id = ?")
+		.bind(i64::MAX)
+		.bind(&key)
+		.execute(&pool)
+		.await
+		.unwrap();
+
+	assert_eq!(db.peek(&key).await.unwrap().user_id, "uid");
+	assert!(db.peek("unknown").await.is_none());
+
+	// peeking must not refresh the timeout
+	let expires_at: (i64,) = sqlx::query_as(
+		"SELECT expires_at FROM sessions WHERE id = ?",
+	)
+	.bind(&key)
+	.fetch_one(&pool)
+	.await
+	.unwrap();
+	assert_eq!(expires_at.0, i64::MAX);
+}
+
+#[tokio::test]
+async fn test_session_revoke_all() {
+	let tmp = TempDb::new();
+	let db = SqliteSessionDB::new(tmp.pool().await).await.unwrap();
+
+	let user_id = uuid::Uuid::new_v4().to_string();
+	let s1 = db.create(Session::new(&user_id)).await.unwrap();
+	let s2 = db.create(Session::new(&user_id)).await.unwrap();
+	let other = db.create(Session::new("other")).await.unwrap();
+	db.invalidate(&s2).await.unwrap();
+
+	let mut sessions = db.list_for_user(&user_id).await.unwrap();
+	sessions.sort_by_key(|(key, _)| key != &s1);
+	assert_eq!(sessions.len(), 2);
+	assert!(sessions[0].1.valid);
+	assert!(!sessions[1].1.valid);
+
+	assert_eq!(
+		db.invalidate_all_for_user(&user_id).await.unwrap(),
+		1
+	);
+	assert!(!db.get(&s1).await.unwrap().valid);
+	assert!(db.get(&other).await.unwrap().valid);
+}
+
+#[tokio::test]
+async fn test_session_device() {
+	let tmp = TempDb::new();
+	let db = SqliteSessionDB::new(tmp.pool().await).await.unwrap();
+
+	let session = Session::new("uid")
+		.with_device(Some("device".into()), Some("ios".into()))
+		.with_locale(Some("DE".into()), Some("de".into()));
+	let key = db.create(session.clone()).await.unwrap();
+
+	assert_eq!(db.get(&key).await.unwrap(), session);
+}
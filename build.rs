@@ -52,6 +52,29 @@ fn main() {
 		.run()
 		.unwrap();
 
+	// missing json fields should fall back to their proto3 defaults,
+	// otherwise adding a field breaks every json client not sending it
+	let schema_rs = "src/schema/schema.rs";
+	let generated = std::fs::read_to_string(schema_rs)
+		.expect("failed to read generated schema");
+	let serde_derive =
+		"derive(::serde::Serialize, ::serde::Deserialize))]\npub struct";
+	// fail loudly if the codegen output changes instead of silently
+	// dropping the defaults
+	assert_eq!(
+		generated.matches(serde_derive).count(),
+		generated.matches("\npub struct ").count(),
+		"serde derive not found on every generated struct"
+	);
+	std::fs::write(
+		schema_rs,
+		generated.replace(
+			serde_derive,
+			"derive(::serde::Serialize, ::serde::Deserialize))]\n#[cfg_attr(feature = \"with-serde\", serde(default))]\npub struct",
+		),
+	)
+	.expect("failed to write generated schema");
+
 	let dest_path =
 		std::path::Path::new(&out_dir).join("get_schema.rs");
 	std::fs::write(
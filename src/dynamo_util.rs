@@ -5,8 +5,8 @@ use rusoto_core::{
 };
 use rusoto_dynamodb::{
 	AttributeDefinition, AttributeValue, CreateTableInput, DynamoDb,
-	DynamoDbClient, KeySchemaElement, ListTablesInput,
-	ProvisionedThroughput,
+	DynamoDbClient, GlobalSecondaryIndex, KeySchemaElement,
+	ListTablesInput, Projection, ProvisionedThroughput,
 };
 use std::collections::HashMap;
 
@@ -32,6 +32,27 @@ pub type DynamoHashMap = HashMap<String, AttributeValue>;
 /// # Errors
 /// fails with network errors
 pub async fn table_init<DB>(db: &DB, table: &str) -> Result<()>
+where
+	DB: DynamoDb + Clone + Send + Sync,
+{
+	table_init_with_indices(db, table, &[]).await
+}
+
+/// name of the global secondary index created for `attribute` by [`table_init_with_indices`]
+#[must_use]
+pub fn index_name(attribute: &str) -> String {
+	format!("{attribute}-index")
+}
+
+/// same as [`table_init`] but additionally creates a global secondary index
+/// (see [`index_name`]) for each of the given string `indices` attributes
+/// # Errors
+/// fails with network errors
+pub async fn table_init_with_indices<DB>(
+	db: &DB,
+	table: &str,
+	indices: &[&str],
+) -> Result<()>
 where
 	DB: DynamoDb + Clone + Send + Sync,
 {
@@ -57,6 +78,41 @@ where
 
 		tracing::info!("create table: {}", table);
 
+		let mut attribute_definitions = vec![AttributeDefinition {
+			attribute_name: "id".into(),
+			attribute_type: "S".into(),
+		}];
+		attribute_definitions.extend(indices.iter().map(|attr| {
+			AttributeDefinition {
+				attribute_name: (*attr).to_string(),
+				attribute_type: "S".into(),
+			}
+		}));
+
+		let global_secondary_indexes =
+			(!indices.is_empty()).then(|| {
+				indices
+					.iter()
+					.map(|attr| GlobalSecondaryIndex {
+						index_name: index_name(attr),
+						key_schema: vec![KeySchemaElement {
+							attribute_name: (*attr).to_string(),
+							key_type: "HASH".into(),
+						}],
+						projection: Projection {
+							projection_type: Some("ALL".into()),
+							..Projection::default()
+						},
+						provisioned_throughput: Some(
+							ProvisionedThroughput {
+								read_capacity_units: 1,
+								write_capacity_units: 1,
+							},
+						),
+					})
+					.collect()
+			});
+
 		let _res = db
 			.create_table(CreateTableInput {
 				table_name: table.into(),
@@ -64,10 +120,8 @@ where
 					attribute_name: "id".into(),
 					key_type: "HASH".into(),
 				}],
-				attribute_definitions: vec![AttributeDefinition {
-					attribute_name: "id".into(),
-					attribute_type: "S".into(),
-				}],
+				attribute_definitions,
+				global_secondary_indexes,
 				provisioned_throughput: Some(ProvisionedThroughput {
 					read_capacity_units: 1,
 					write_capacity_units: 1,
@@ -2,7 +2,8 @@ use rusoto_core::{
 	credential::CredentialsError, request::TlsError, RusotoError,
 };
 use rusoto_dynamodb::{
-	CreateTableError, ListTablesError, PutItemError,
+	CreateTableError, DeleteItemError, ListTablesError, PutItemError,
+	QueryError, ScanError,
 };
 use thiserror::Error;
 
@@ -14,6 +15,9 @@ pub enum Error {
 	#[error("io error: {0}")]
 	Io(#[from] std::io::Error),
 
+	#[error("http error: {0}")]
+	Http(#[from] warp::hyper::Error),
+
 	#[error("aws error: {0}")]
 	RusotoPutItem(#[from] RusotoError<PutItemError>),
 
@@ -26,6 +30,15 @@ pub enum Error {
 	#[error("aws error: {0}")]
 	RusotoCreateTable(#[from] RusotoError<CreateTableError>),
 
+	#[error("aws error: {0}")]
+	RusotoQuery(#[from] RusotoError<QueryError>),
+
+	#[error("aws error: {0}")]
+	RusotoScan(#[from] RusotoError<ScanError>),
+
+	#[error("aws error: {0}")]
+	RusotoDeleteItem(#[from] RusotoError<DeleteItemError>),
+
 	#[error("aws error: {0}")]
 	RusotoCredentials(#[from] CredentialsError),
 
@@ -34,6 +47,18 @@ pub enum Error {
 
 	#[error("DynamoDeserializeError for field: {0}")]
 	DynamoDeserialize(&'static str),
+
+	#[cfg(feature = "redis")]
+	#[error("redis error: {0}")]
+	Redis(#[from] deadpool_redis::redis::RedisError),
+
+	#[cfg(feature = "redis")]
+	#[error("redis pool error: {0}")]
+	RedisPool(#[from] deadpool_redis::PoolError),
+
+	#[cfg(feature = "maxminddb")]
+	#[error("maxminddb error: {0}")]
+	MaxMind(#[from] maxminddb::MaxMindDBError),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -10,12 +10,28 @@ pub enum SessionFailure {
 
 impl Reject for SessionFailure {}
 
+/// missing or wrong admin key
+#[derive(Debug)]
+pub struct AdminFailure;
+
+impl Reject for AdminFailure {}
+
 #[allow(clippy::missing_errors_doc)]
 //TODO: this a nightly only warning currently
 #[allow(clippy::unused_async)]
 pub async fn handle_rejection(
 	err: Rejection,
 ) -> Result<impl Reply, Infallible> {
+	if err.find::<AdminFailure>().is_some() {
+		return Ok(warp::reply::with_status(
+			crate::pbwarp::protobuf_reply(
+				&RejectionResponse::default(),
+				None,
+			),
+			StatusCode::UNAUTHORIZED,
+		));
+	}
+
 	err.find::<SessionFailure>().map_or_else(
         || {
 			tracing::error!("unhandled rejection {:?}", err);
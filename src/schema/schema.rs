@@ -25,10 +25,13 @@
 
 #[derive(PartialEq,Clone,Default)]
 #[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "with-serde", serde(default))]
 pub struct RegisterRequest {
     // message fields
     pub clientVersion: u32,
     pub clientLanguage: ::std::string::String,
+    pub deviceId: ::std::string::String,
+    pub platform: ::std::string::String,
     // special fields
     #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
@@ -87,6 +90,58 @@ impl RegisterRequest {
     pub fn take_clientLanguage(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.clientLanguage, ::std::string::String::new())
     }
+
+    // string deviceId = 3;
+
+
+    pub fn get_deviceId(&self) -> &str {
+        &self.deviceId
+    }
+    pub fn clear_deviceId(&mut self) {
+        self.deviceId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deviceId(&mut self, v: ::std::string::String) {
+        self.deviceId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_deviceId(&mut self) -> &mut ::std::string::String {
+        &mut self.deviceId
+    }
+
+    // Take field
+    pub fn take_deviceId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.deviceId, ::std::string::String::new())
+    }
+
+    // string platform = 4;
+
+
+    pub fn get_platform(&self) -> &str {
+        &self.platform
+    }
+    pub fn clear_platform(&mut self) {
+        self.platform.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_platform(&mut self, v: ::std::string::String) {
+        self.platform = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_platform(&mut self) -> &mut ::std::string::String {
+        &mut self.platform
+    }
+
+    // Take field
+    pub fn take_platform(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.platform, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for RegisterRequest {
@@ -108,6 +163,12 @@ impl ::protobuf::Message for RegisterRequest {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.clientLanguage)?;
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.deviceId)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.platform)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -126,6 +187,12 @@ impl ::protobuf::Message for RegisterRequest {
         if !self.clientLanguage.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.clientLanguage);
         }
+        if !self.deviceId.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.deviceId);
+        }
+        if !self.platform.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.platform);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -138,6 +205,12 @@ impl ::protobuf::Message for RegisterRequest {
         if !self.clientLanguage.is_empty() {
             os.write_string(2, &self.clientLanguage)?;
         }
+        if !self.deviceId.is_empty() {
+            os.write_string(3, &self.deviceId)?;
+        }
+        if !self.platform.is_empty() {
+            os.write_string(4, &self.platform)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -186,6 +259,16 @@ impl ::protobuf::Message for RegisterRequest {
                 |m: &RegisterRequest| { &m.clientLanguage },
                 |m: &mut RegisterRequest| { &mut m.clientLanguage },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "deviceId",
+                |m: &RegisterRequest| { &m.deviceId },
+                |m: &mut RegisterRequest| { &mut m.deviceId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "platform",
+                |m: &RegisterRequest| { &m.platform },
+                |m: &mut RegisterRequest| { &mut m.platform },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<RegisterRequest>(
                 "RegisterRequest",
                 fields,
@@ -204,6 +287,8 @@ impl ::protobuf::Clear for RegisterRequest {
     fn clear(&mut self) {
         self.clientVersion = 0;
         self.clientLanguage.clear();
+        self.deviceId.clear();
+        self.platform.clear();
         self.unknown_fields.clear();
     }
 }
@@ -222,11 +307,14 @@ impl ::protobuf::reflect::ProtobufValue for RegisterRequest {
 
 #[derive(PartialEq,Clone,Default)]
 #[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "with-serde", serde(default))]
 pub struct LoginRequest {
     // message fields
     pub user: ::protobuf::SingularPtrField<UserCredentials>,
     pub clientVersion: u32,
     pub clientLanguage: ::std::string::String,
+    pub deviceId: ::std::string::String,
+    pub platform: ::std::string::String,
     // special fields
     #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
@@ -318,6 +406,58 @@ impl LoginRequest {
     pub fn take_clientLanguage(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.clientLanguage, ::std::string::String::new())
     }
+
+    // string deviceId = 4;
+
+
+    pub fn get_deviceId(&self) -> &str {
+        &self.deviceId
+    }
+    pub fn clear_deviceId(&mut self) {
+        self.deviceId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deviceId(&mut self, v: ::std::string::String) {
+        self.deviceId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_deviceId(&mut self) -> &mut ::std::string::String {
+        &mut self.deviceId
+    }
+
+    // Take field
+    pub fn take_deviceId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.deviceId, ::std::string::String::new())
+    }
+
+    // string platform = 5;
+
+
+    pub fn get_platform(&self) -> &str {
+        &self.platform
+    }
+    pub fn clear_platform(&mut self) {
+        self.platform.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_platform(&mut self, v: ::std::string::String) {
+        self.platform = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_platform(&mut self) -> &mut ::std::string::String {
+        &mut self.platform
+    }
+
+    // Take field
+    pub fn take_platform(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.platform, ::std::string::String::new())
+    }
 }
 
 impl ::protobuf::Message for LoginRequest {
@@ -347,6 +487,12 @@ impl ::protobuf::Message for LoginRequest {
                 3 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.clientLanguage)?;
                 },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.deviceId)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.platform)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -369,6 +515,12 @@ impl ::protobuf::Message for LoginRequest {
         if !self.clientLanguage.is_empty() {
             my_size += ::protobuf::rt::string_size(3, &self.clientLanguage);
         }
+        if !self.deviceId.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.deviceId);
+        }
+        if !self.platform.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.platform);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -386,6 +538,12 @@ impl ::protobuf::Message for LoginRequest {
         if !self.clientLanguage.is_empty() {
             os.write_string(3, &self.clientLanguage)?;
         }
+        if !self.deviceId.is_empty() {
+            os.write_string(4, &self.deviceId)?;
+        }
+        if !self.platform.is_empty() {
+            os.write_string(5, &self.platform)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -439,6 +597,16 @@ impl ::protobuf::Message for LoginRequest {
                 |m: &LoginRequest| { &m.clientLanguage },
                 |m: &mut LoginRequest| { &mut m.clientLanguage },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "deviceId",
+                |m: &LoginRequest| { &m.deviceId },
+                |m: &mut LoginRequest| { &mut m.deviceId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "platform",
+                |m: &LoginRequest| { &m.platform },
+                |m: &mut LoginRequest| { &mut m.platform },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<LoginRequest>(
                 "LoginRequest",
                 fields,
@@ -458,6 +626,8 @@ impl ::protobuf::Clear for LoginRequest {
         self.user.clear();
         self.clientVersion = 0;
         self.clientLanguage.clear();
+        self.deviceId.clear();
+        self.platform.clear();
         self.unknown_fields.clear();
     }
 }
@@ -476,9 +646,11 @@ impl ::protobuf::reflect::ProtobufValue for LoginRequest {
 
 #[derive(PartialEq,Clone,Default)]
 #[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "with-serde", serde(default))]
 pub struct LoginResponse {
     // message fields
     pub isOutdated: bool,
+    pub update: ::protobuf::SingularPtrField<UpdateInfo>,
     // special fields
     #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
@@ -511,10 +683,48 @@ impl LoginResponse {
     pub fn set_isOutdated(&mut self, v: bool) {
         self.isOutdated = v;
     }
+
+    // .UpdateInfo update = 2;
+
+
+    pub fn get_update(&self) -> &UpdateInfo {
+        self.update.as_ref().unwrap_or_else(|| <UpdateInfo as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_update(&mut self) {
+        self.update.clear();
+    }
+
+    pub fn has_update(&self) -> bool {
+        self.update.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_update(&mut self, v: UpdateInfo) {
+        self.update = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_update(&mut self) -> &mut UpdateInfo {
+        if self.update.is_none() {
+            self.update.set_default();
+        }
+        self.update.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_update(&mut self) -> UpdateInfo {
+        self.update.take().unwrap_or_else(|| UpdateInfo::new())
+    }
 }
 
 impl ::protobuf::Message for LoginResponse {
     fn is_initialized(&self) -> bool {
+        for v in &self.update {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -529,6 +739,9 @@ impl ::protobuf::Message for LoginResponse {
                     let tmp = is.read_bool()?;
                     self.isOutdated = tmp;
                 },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.update)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -544,6 +757,10 @@ impl ::protobuf::Message for LoginResponse {
         if self.isOutdated != false {
             my_size += 2;
         }
+        if let Some(ref v) = self.update.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -553,6 +770,11 @@ impl ::protobuf::Message for LoginResponse {
         if self.isOutdated != false {
             os.write_bool(1, self.isOutdated)?;
         }
+        if let Some(ref v) = self.update.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -596,6 +818,11 @@ impl ::protobuf::Message for LoginResponse {
                 |m: &LoginResponse| { &m.isOutdated },
                 |m: &mut LoginResponse| { &mut m.isOutdated },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UpdateInfo>>(
+                "update",
+                |m: &LoginResponse| { &m.update },
+                |m: &mut LoginResponse| { &mut m.update },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<LoginResponse>(
                 "LoginResponse",
                 fields,
@@ -613,6 +840,7 @@ impl ::protobuf::Message for LoginResponse {
 impl ::protobuf::Clear for LoginResponse {
     fn clear(&mut self) {
         self.isOutdated = false;
+        self.update.clear();
         self.unknown_fields.clear();
     }
 }
@@ -631,10 +859,13 @@ impl ::protobuf::reflect::ProtobufValue for LoginResponse {
 
 #[derive(PartialEq,Clone,Default)]
 #[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
-pub struct UserCredentials {
+#[cfg_attr(feature = "with-serde", serde(default))]
+pub struct UpdateInfo {
     // message fields
-    pub id: ::std::string::String,
-    pub secret: ::std::string::String,
+    pub minVersion: u32,
+    pub latestVersion: u32,
+    pub forceUpdate: bool,
+    pub storeUrl: ::std::string::String,
     // special fields
     #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
@@ -642,71 +873,90 @@ pub struct UserCredentials {
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a UserCredentials {
-    fn default() -> &'a UserCredentials {
-        <UserCredentials as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a UpdateInfo {
+    fn default() -> &'a UpdateInfo {
+        <UpdateInfo as ::protobuf::Message>::default_instance()
     }
 }
 
-impl UserCredentials {
-    pub fn new() -> UserCredentials {
+impl UpdateInfo {
+    pub fn new() -> UpdateInfo {
         ::std::default::Default::default()
     }
 
-    // string id = 1;
+    // uint32 minVersion = 1;
 
 
-    pub fn get_id(&self) -> &str {
-        &self.id
+    pub fn get_minVersion(&self) -> u32 {
+        self.minVersion
     }
-    pub fn clear_id(&mut self) {
-        self.id.clear();
+    pub fn clear_minVersion(&mut self) {
+        self.minVersion = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: ::std::string::String) {
-        self.id = v;
+    pub fn set_minVersion(&mut self, v: u32) {
+        self.minVersion = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_id(&mut self) -> &mut ::std::string::String {
-        &mut self.id
+    // uint32 latestVersion = 2;
+
+
+    pub fn get_latestVersion(&self) -> u32 {
+        self.latestVersion
+    }
+    pub fn clear_latestVersion(&mut self) {
+        self.latestVersion = 0;
     }
 
-    // Take field
-    pub fn take_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    // Param is passed by value, moved
+    pub fn set_latestVersion(&mut self, v: u32) {
+        self.latestVersion = v;
     }
 
-    // string secret = 2;
+    // bool forceUpdate = 3;
 
 
-    pub fn get_secret(&self) -> &str {
-        &self.secret
+    pub fn get_forceUpdate(&self) -> bool {
+        self.forceUpdate
     }
-    pub fn clear_secret(&mut self) {
-        self.secret.clear();
+    pub fn clear_forceUpdate(&mut self) {
+        self.forceUpdate = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_secret(&mut self, v: ::std::string::String) {
-        self.secret = v;
+    pub fn set_forceUpdate(&mut self, v: bool) {
+        self.forceUpdate = v;
+    }
+
+    // string storeUrl = 4;
+
+
+    pub fn get_storeUrl(&self) -> &str {
+        &self.storeUrl
+    }
+    pub fn clear_storeUrl(&mut self) {
+        self.storeUrl.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_storeUrl(&mut self, v: ::std::string::String) {
+        self.storeUrl = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_secret(&mut self) -> &mut ::std::string::String {
-        &mut self.secret
+    pub fn mut_storeUrl(&mut self) -> &mut ::std::string::String {
+        &mut self.storeUrl
     }
 
     // Take field
-    pub fn take_secret(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.secret, ::std::string::String::new())
+    pub fn take_storeUrl(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.storeUrl, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for UserCredentials {
+impl ::protobuf::Message for UpdateInfo {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -716,10 +966,28 @@ impl ::protobuf::Message for UserCredentials {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.minVersion = tmp;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.secret)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.latestVersion = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.forceUpdate = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.storeUrl)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -733,11 +1001,17 @@ impl ::protobuf::Message for UserCredentials {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.id);
+        if self.minVersion != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.minVersion, ::protobuf::wire_format::WireTypeVarint);
         }
-        if !self.secret.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.secret);
+        if self.latestVersion != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.latestVersion, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.forceUpdate != false {
+            my_size += 2;
+        }
+        if !self.storeUrl.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.storeUrl);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -745,11 +1019,17 @@ impl ::protobuf::Message for UserCredentials {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.id.is_empty() {
-            os.write_string(1, &self.id)?;
+        if self.minVersion != 0 {
+            os.write_uint32(1, self.minVersion)?;
         }
-        if !self.secret.is_empty() {
-            os.write_string(2, &self.secret)?;
+        if self.latestVersion != 0 {
+            os.write_uint32(2, self.latestVersion)?;
+        }
+        if self.forceUpdate != false {
+            os.write_bool(3, self.forceUpdate)?;
+        }
+        if !self.storeUrl.is_empty() {
+            os.write_string(4, &self.storeUrl)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -781,53 +1061,65 @@ impl ::protobuf::Message for UserCredentials {
         Self::descriptor_static()
     }
 
-    fn new() -> UserCredentials {
-        UserCredentials::new()
+    fn new() -> UpdateInfo {
+        UpdateInfo::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "id",
-                |m: &UserCredentials| { &m.id },
-                |m: &mut UserCredentials| { &mut m.id },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "minVersion",
+                |m: &UpdateInfo| { &m.minVersion },
+                |m: &mut UpdateInfo| { &mut m.minVersion },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "latestVersion",
+                |m: &UpdateInfo| { &m.latestVersion },
+                |m: &mut UpdateInfo| { &mut m.latestVersion },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "forceUpdate",
+                |m: &UpdateInfo| { &m.forceUpdate },
+                |m: &mut UpdateInfo| { &mut m.forceUpdate },
             ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "secret",
-                |m: &UserCredentials| { &m.secret },
-                |m: &mut UserCredentials| { &mut m.secret },
+                "storeUrl",
+                |m: &UpdateInfo| { &m.storeUrl },
+                |m: &mut UpdateInfo| { &mut m.storeUrl },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UserCredentials>(
-                "UserCredentials",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateInfo>(
+                "UpdateInfo",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static UserCredentials {
-        static instance: ::protobuf::rt::LazyV2<UserCredentials> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(UserCredentials::new)
+    fn default_instance() -> &'static UpdateInfo {
+        static instance: ::protobuf::rt::LazyV2<UpdateInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UpdateInfo::new)
     }
 }
 
-impl ::protobuf::Clear for UserCredentials {
+impl ::protobuf::Clear for UpdateInfo {
     fn clear(&mut self) {
-        self.id.clear();
-        self.secret.clear();
+        self.minVersion = 0;
+        self.latestVersion = 0;
+        self.forceUpdate = false;
+        self.storeUrl.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for UserCredentials {
+impl ::std::fmt::Debug for UpdateInfo {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for UserCredentials {
+impl ::protobuf::reflect::ProtobufValue for UpdateInfo {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
@@ -835,10 +1127,11 @@ impl ::protobuf::reflect::ProtobufValue for UserCredentials {
 
 #[derive(PartialEq,Clone,Default)]
 #[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
-pub struct RegisterResponse {
+#[cfg_attr(feature = "with-serde", serde(default))]
+pub struct UserCredentials {
     // message fields
-    pub user: ::protobuf::SingularPtrField<UserCredentials>,
-    pub isOutdated: bool,
+    pub id: ::std::string::String,
+    pub secret: ::std::string::String,
     // special fields
     #[cfg_attr(feature = "with-serde", serde(skip))]
     pub unknown_fields: ::protobuf::UnknownFields,
@@ -846,49 +1139,255 @@ pub struct RegisterResponse {
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a RegisterResponse {
-    fn default() -> &'a RegisterResponse {
-        <RegisterResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a UserCredentials {
+    fn default() -> &'a UserCredentials {
+        <UserCredentials as ::protobuf::Message>::default_instance()
     }
 }
 
-impl RegisterResponse {
-    pub fn new() -> RegisterResponse {
+impl UserCredentials {
+    pub fn new() -> UserCredentials {
         ::std::default::Default::default()
     }
 
-    // .UserCredentials user = 1;
+    // string id = 1;
 
 
-    pub fn get_user(&self) -> &UserCredentials {
-        self.user.as_ref().unwrap_or_else(|| <UserCredentials as ::protobuf::Message>::default_instance())
-    }
-    pub fn clear_user(&mut self) {
-        self.user.clear();
+    pub fn get_id(&self) -> &str {
+        &self.id
     }
-
-    pub fn has_user(&self) -> bool {
-        self.user.is_some()
+    pub fn clear_id(&mut self) {
+        self.id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_user(&mut self, v: UserCredentials) {
-        self.user = ::protobuf::SingularPtrField::some(v);
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_user(&mut self) -> &mut UserCredentials {
-        if self.user.is_none() {
-            self.user.set_default();
-        }
-        self.user.as_mut().unwrap()
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
     }
 
     // Take field
-    pub fn take_user(&mut self) -> UserCredentials {
-        self.user.take().unwrap_or_else(|| UserCredentials::new())
-    }
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string secret = 2;
+
+
+    pub fn get_secret(&self) -> &str {
+        &self.secret
+    }
+    pub fn clear_secret(&mut self) {
+        self.secret.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secret(&mut self, v: ::std::string::String) {
+        self.secret = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_secret(&mut self) -> &mut ::std::string::String {
+        &mut self.secret
+    }
+
+    // Take field
+    pub fn take_secret(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.secret, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for UserCredentials {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.secret)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.secret.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.secret);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.secret.is_empty() {
+            os.write_string(2, &self.secret)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UserCredentials {
+        UserCredentials::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &UserCredentials| { &m.id },
+                |m: &mut UserCredentials| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "secret",
+                |m: &UserCredentials| { &m.secret },
+                |m: &mut UserCredentials| { &mut m.secret },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UserCredentials>(
+                "UserCredentials",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UserCredentials {
+        static instance: ::protobuf::rt::LazyV2<UserCredentials> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UserCredentials::new)
+    }
+}
+
+impl ::protobuf::Clear for UserCredentials {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.secret.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UserCredentials {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UserCredentials {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "with-serde", serde(default))]
+pub struct RegisterResponse {
+    // message fields
+    pub user: ::protobuf::SingularPtrField<UserCredentials>,
+    pub isOutdated: bool,
+    pub update: ::protobuf::SingularPtrField<UpdateInfo>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RegisterResponse {
+    fn default() -> &'a RegisterResponse {
+        <RegisterResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RegisterResponse {
+    pub fn new() -> RegisterResponse {
+        ::std::default::Default::default()
+    }
+
+    // .UserCredentials user = 1;
+
+
+    pub fn get_user(&self) -> &UserCredentials {
+        self.user.as_ref().unwrap_or_else(|| <UserCredentials as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_user(&mut self) {
+        self.user.clear();
+    }
+
+    pub fn has_user(&self) -> bool {
+        self.user.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user(&mut self, v: UserCredentials) {
+        self.user = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user(&mut self) -> &mut UserCredentials {
+        if self.user.is_none() {
+            self.user.set_default();
+        }
+        self.user.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_user(&mut self) -> UserCredentials {
+        self.user.take().unwrap_or_else(|| UserCredentials::new())
+    }
 
     // bool isOutdated = 2;
 
@@ -904,6 +1403,39 @@ impl RegisterResponse {
     pub fn set_isOutdated(&mut self, v: bool) {
         self.isOutdated = v;
     }
+
+    // .UpdateInfo update = 3;
+
+
+    pub fn get_update(&self) -> &UpdateInfo {
+        self.update.as_ref().unwrap_or_else(|| <UpdateInfo as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_update(&mut self) {
+        self.update.clear();
+    }
+
+    pub fn has_update(&self) -> bool {
+        self.update.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_update(&mut self, v: UpdateInfo) {
+        self.update = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_update(&mut self) -> &mut UpdateInfo {
+        if self.update.is_none() {
+            self.update.set_default();
+        }
+        self.update.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_update(&mut self) -> UpdateInfo {
+        self.update.take().unwrap_or_else(|| UpdateInfo::new())
+    }
 }
 
 impl ::protobuf::Message for RegisterResponse {
@@ -913,6 +1445,11 @@ impl ::protobuf::Message for RegisterResponse {
                 return false;
             }
         };
+        for v in &self.update {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -930,6 +1467,9 @@ impl ::protobuf::Message for RegisterResponse {
                     let tmp = is.read_bool()?;
                     self.isOutdated = tmp;
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.update)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -949,6 +1489,10 @@ impl ::protobuf::Message for RegisterResponse {
         if self.isOutdated != false {
             my_size += 2;
         }
+        if let Some(ref v) = self.update.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -963,6 +1507,11 @@ impl ::protobuf::Message for RegisterResponse {
         if self.isOutdated != false {
             os.write_bool(2, self.isOutdated)?;
         }
+        if let Some(ref v) = self.update.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1011,6 +1560,11 @@ impl ::protobuf::Message for RegisterResponse {
                 |m: &RegisterResponse| { &m.isOutdated },
                 |m: &mut RegisterResponse| { &mut m.isOutdated },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UpdateInfo>>(
+                "update",
+                |m: &RegisterResponse| { &m.update },
+                |m: &mut RegisterResponse| { &mut m.update },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<RegisterResponse>(
                 "RegisterResponse",
                 fields,
@@ -1029,6 +1583,7 @@ impl ::protobuf::Clear for RegisterResponse {
     fn clear(&mut self) {
         self.user.clear();
         self.isOutdated = false;
+        self.update.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1047,6 +1602,7 @@ impl ::protobuf::reflect::ProtobufValue for RegisterResponse {
 
 #[derive(PartialEq,Clone,Default)]
 #[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "with-serde", serde(default))]
 pub struct RejectionResponse {
     // message fields
     pub sessionFilterRejection: RejectionResponse_SessionFilterRejection,
@@ -1250,23 +1806,1003 @@ impl ::protobuf::reflect::ProtobufValue for RejectionResponse_SessionFilterRejec
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "with-serde", serde(default))]
+pub struct RevokeSessionsResponse {
+    // message fields
+    pub revoked: u32,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RevokeSessionsResponse {
+    fn default() -> &'a RevokeSessionsResponse {
+        <RevokeSessionsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RevokeSessionsResponse {
+    pub fn new() -> RevokeSessionsResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint32 revoked = 1;
+
+
+    pub fn get_revoked(&self) -> u32 {
+        self.revoked
+    }
+    pub fn clear_revoked(&mut self) {
+        self.revoked = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revoked(&mut self, v: u32) {
+        self.revoked = v;
+    }
+}
+
+impl ::protobuf::Message for RevokeSessionsResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.revoked = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.revoked != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.revoked, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.revoked != 0 {
+            os.write_uint32(1, self.revoked)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RevokeSessionsResponse {
+        RevokeSessionsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "revoked",
+                |m: &RevokeSessionsResponse| { &m.revoked },
+                |m: &mut RevokeSessionsResponse| { &mut m.revoked },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RevokeSessionsResponse>(
+                "RevokeSessionsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RevokeSessionsResponse {
+        static instance: ::protobuf::rt::LazyV2<RevokeSessionsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RevokeSessionsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RevokeSessionsResponse {
+    fn clear(&mut self) {
+        self.revoked = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RevokeSessionsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RevokeSessionsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "with-serde", serde(default))]
+pub struct RestoreUserResponse {
+    // message fields
+    pub restored: bool,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RestoreUserResponse {
+    fn default() -> &'a RestoreUserResponse {
+        <RestoreUserResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RestoreUserResponse {
+    pub fn new() -> RestoreUserResponse {
+        ::std::default::Default::default()
+    }
+
+    // bool restored = 1;
+
+
+    pub fn get_restored(&self) -> bool {
+        self.restored
+    }
+    pub fn clear_restored(&mut self) {
+        self.restored = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_restored(&mut self, v: bool) {
+        self.restored = v;
+    }
+}
+
+impl ::protobuf::Message for RestoreUserResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.restored = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.restored != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.restored != false {
+            os.write_bool(1, self.restored)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RestoreUserResponse {
+        RestoreUserResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "restored",
+                |m: &RestoreUserResponse| { &m.restored },
+                |m: &mut RestoreUserResponse| { &mut m.restored },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RestoreUserResponse>(
+                "RestoreUserResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RestoreUserResponse {
+        static instance: ::protobuf::rt::LazyV2<RestoreUserResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RestoreUserResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for RestoreUserResponse {
+    fn clear(&mut self) {
+        self.restored = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RestoreUserResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RestoreUserResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "with-serde", serde(default))]
+pub struct AdminUserResponse {
+    // message fields
+    pub id: ::std::string::String,
+    pub version: u64,
+    pub country: ::std::string::String,
+    pub language: ::std::string::String,
+    pub deletedAt: i64,
+    pub sessions: ::protobuf::RepeatedField<AdminSession>,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AdminUserResponse {
+    fn default() -> &'a AdminUserResponse {
+        <AdminUserResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AdminUserResponse {
+    pub fn new() -> AdminUserResponse {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // uint64 version = 2;
+
+
+    pub fn get_version(&self) -> u64 {
+        self.version
+    }
+    pub fn clear_version(&mut self) {
+        self.version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: u64) {
+        self.version = v;
+    }
+
+    // string country = 3;
+
+
+    pub fn get_country(&self) -> &str {
+        &self.country
+    }
+    pub fn clear_country(&mut self) {
+        self.country.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_country(&mut self, v: ::std::string::String) {
+        self.country = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_country(&mut self) -> &mut ::std::string::String {
+        &mut self.country
+    }
+
+    // Take field
+    pub fn take_country(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.country, ::std::string::String::new())
+    }
+
+    // string language = 4;
+
+
+    pub fn get_language(&self) -> &str {
+        &self.language
+    }
+    pub fn clear_language(&mut self) {
+        self.language.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_language(&mut self, v: ::std::string::String) {
+        self.language = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_language(&mut self) -> &mut ::std::string::String {
+        &mut self.language
+    }
+
+    // Take field
+    pub fn take_language(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.language, ::std::string::String::new())
+    }
+
+    // int64 deletedAt = 5;
+
+
+    pub fn get_deletedAt(&self) -> i64 {
+        self.deletedAt
+    }
+    pub fn clear_deletedAt(&mut self) {
+        self.deletedAt = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deletedAt(&mut self, v: i64) {
+        self.deletedAt = v;
+    }
+
+    // repeated .AdminSession sessions = 6;
+
+
+    pub fn get_sessions(&self) -> &[AdminSession] {
+        &self.sessions
+    }
+    pub fn clear_sessions(&mut self) {
+        self.sessions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sessions(&mut self, v: ::protobuf::RepeatedField<AdminSession>) {
+        self.sessions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sessions(&mut self) -> &mut ::protobuf::RepeatedField<AdminSession> {
+        &mut self.sessions
+    }
+
+    // Take field
+    pub fn take_sessions(&mut self) -> ::protobuf::RepeatedField<AdminSession> {
+        ::std::mem::replace(&mut self.sessions, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for AdminUserResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.sessions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.version = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.country)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.language)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.deletedAt = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.sessions)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if self.version != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.country.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.country);
+        }
+        if !self.language.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.language);
+        }
+        if self.deletedAt != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.deletedAt, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.sessions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if self.version != 0 {
+            os.write_uint64(2, self.version)?;
+        }
+        if !self.country.is_empty() {
+            os.write_string(3, &self.country)?;
+        }
+        if !self.language.is_empty() {
+            os.write_string(4, &self.language)?;
+        }
+        if self.deletedAt != 0 {
+            os.write_int64(5, self.deletedAt)?;
+        }
+        for v in &self.sessions {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AdminUserResponse {
+        AdminUserResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &AdminUserResponse| { &m.id },
+                |m: &mut AdminUserResponse| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "version",
+                |m: &AdminUserResponse| { &m.version },
+                |m: &mut AdminUserResponse| { &mut m.version },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "country",
+                |m: &AdminUserResponse| { &m.country },
+                |m: &mut AdminUserResponse| { &mut m.country },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "language",
+                |m: &AdminUserResponse| { &m.language },
+                |m: &mut AdminUserResponse| { &mut m.language },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "deletedAt",
+                |m: &AdminUserResponse| { &m.deletedAt },
+                |m: &mut AdminUserResponse| { &mut m.deletedAt },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AdminSession>>(
+                "sessions",
+                |m: &AdminUserResponse| { &m.sessions },
+                |m: &mut AdminUserResponse| { &mut m.sessions },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AdminUserResponse>(
+                "AdminUserResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AdminUserResponse {
+        static instance: ::protobuf::rt::LazyV2<AdminUserResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AdminUserResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for AdminUserResponse {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.version = 0;
+        self.country.clear();
+        self.language.clear();
+        self.deletedAt = 0;
+        self.sessions.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AdminUserResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AdminUserResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+#[cfg_attr(feature = "with-serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "with-serde", serde(default))]
+pub struct AdminSession {
+    // message fields
+    pub id: ::std::string::String,
+    pub valid: bool,
+    pub deviceId: ::std::string::String,
+    pub platform: ::std::string::String,
+    // special fields
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub unknown_fields: ::protobuf::UnknownFields,
+    #[cfg_attr(feature = "with-serde", serde(skip))]
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AdminSession {
+    fn default() -> &'a AdminSession {
+        <AdminSession as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AdminSession {
+    pub fn new() -> AdminSession {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // bool valid = 2;
+
+
+    pub fn get_valid(&self) -> bool {
+        self.valid
+    }
+    pub fn clear_valid(&mut self) {
+        self.valid = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_valid(&mut self, v: bool) {
+        self.valid = v;
+    }
+
+    // string deviceId = 3;
+
+
+    pub fn get_deviceId(&self) -> &str {
+        &self.deviceId
+    }
+    pub fn clear_deviceId(&mut self) {
+        self.deviceId.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deviceId(&mut self, v: ::std::string::String) {
+        self.deviceId = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_deviceId(&mut self) -> &mut ::std::string::String {
+        &mut self.deviceId
+    }
+
+    // Take field
+    pub fn take_deviceId(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.deviceId, ::std::string::String::new())
+    }
+
+    // string platform = 4;
+
+
+    pub fn get_platform(&self) -> &str {
+        &self.platform
+    }
+    pub fn clear_platform(&mut self) {
+        self.platform.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_platform(&mut self, v: ::std::string::String) {
+        self.platform = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_platform(&mut self) -> &mut ::std::string::String {
+        &mut self.platform
+    }
+
+    // Take field
+    pub fn take_platform(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.platform, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for AdminSession {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.valid = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.deviceId)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.platform)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if self.valid != false {
+            my_size += 2;
+        }
+        if !self.deviceId.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.deviceId);
+        }
+        if !self.platform.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.platform);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if self.valid != false {
+            os.write_bool(2, self.valid)?;
+        }
+        if !self.deviceId.is_empty() {
+            os.write_string(3, &self.deviceId)?;
+        }
+        if !self.platform.is_empty() {
+            os.write_string(4, &self.platform)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AdminSession {
+        AdminSession::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &AdminSession| { &m.id },
+                |m: &mut AdminSession| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "valid",
+                |m: &AdminSession| { &m.valid },
+                |m: &mut AdminSession| { &mut m.valid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "deviceId",
+                |m: &AdminSession| { &m.deviceId },
+                |m: &mut AdminSession| { &mut m.deviceId },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "platform",
+                |m: &AdminSession| { &m.platform },
+                |m: &mut AdminSession| { &mut m.platform },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AdminSession>(
+                "AdminSession",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AdminSession {
+        static instance: ::protobuf::rt::LazyV2<AdminSession> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AdminSession::new)
+    }
+}
+
+impl ::protobuf::Clear for AdminSession {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.valid = false;
+        self.deviceId.clear();
+        self.platform.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AdminSession {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AdminSession {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0cschema.proto\x1a\x0frustproto.proto\"e\n\x0fRegisterRequest\x12&\n\
-    \rclientVersion\x18\x01\x20\x01(\rR\rclientVersionB\0\x12(\n\x0eclientLa\
-    nguage\x18\x02\x20\x01(\tR\x0eclientLanguageB\0:\0\"\x8a\x01\n\x0cLoginR\
-    equest\x12&\n\x04user\x18\x01\x20\x01(\x0b2\x10.UserCredentialsR\x04user\
-    B\0\x12&\n\rclientVersion\x18\x02\x20\x01(\rR\rclientVersionB\0\x12(\n\
-    \x0eclientLanguage\x18\x03\x20\x01(\tR\x0eclientLanguageB\0:\0\"3\n\rLog\
-    inResponse\x12\x20\n\nisOutdated\x18\x01\x20\x01(\x08R\nisOutdatedB\0:\0\
-    \"?\n\x0fUserCredentials\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\
-    \x12\x18\n\x06secret\x18\x02\x20\x01(\tR\x06secretB\0:\0\"^\n\x10Registe\
-    rResponse\x12&\n\x04user\x18\x01\x20\x01(\x0b2\x10.UserCredentialsR\x04u\
-    serB\0\x12\x20\n\nisOutdated\x18\x02\x20\x01(\x08R\nisOutdatedB\0:\0\"\
-    \xc4\x01\n\x11RejectionResponse\x12c\n\x16sessionFilterRejection\x18\x01\
-    \x20\x01(\x0e2).RejectionResponse.SessionFilterRejectionR\x16sessionFilt\
-    erRejectionB\0\"H\n\x16SessionFilterRejection\x12\x08\n\x04NONE\x10\0\
-    \x12\x0b\n\x07INVALID\x10\x01\x12\x15\n\x11SESSION_NOT_FOUND\x10\x02\x1a\
-    \0:\0B\x04\xb0\xa8\x08\x01b\x06proto3\
+    \n\x0cschema.proto\x1a\x0frustproto.proto\"\xa1\x01\n\x0fRegisterRequest\
+    \x12&\n\rclientVersion\x18\x01\x20\x01(\rR\rclientVersionB\0\x12(\n\x0ec\
+    lientLanguage\x18\x02\x20\x01(\tR\x0eclientLanguageB\0\x12\x1c\n\x08devi\
+    ceId\x18\x03\x20\x01(\tR\x08deviceIdB\0\x12\x1c\n\x08platform\x18\x04\
+    \x20\x01(\tR\x08platformB\0:\0\"\xc6\x01\n\x0cLoginRequest\x12&\n\x04use\
+    r\x18\x01\x20\x01(\x0b2\x10.UserCredentialsR\x04userB\0\x12&\n\rclientVe\
+    rsion\x18\x02\x20\x01(\rR\rclientVersionB\0\x12(\n\x0eclientLanguage\x18\
+    \x03\x20\x01(\tR\x0eclientLanguageB\0\x12\x1c\n\x08deviceId\x18\x04\x20\
+    \x01(\tR\x08deviceIdB\0\x12\x1c\n\x08platform\x18\x05\x20\x01(\tR\x08pla\
+    tformB\0:\0\"Z\n\rLoginResponse\x12\x20\n\nisOutdated\x18\x01\x20\x01(\
+    \x08R\nisOutdatedB\0\x12%\n\x06update\x18\x02\x20\x01(\x0b2\x0b.UpdateIn\
+    foR\x06updateB\0:\0\"\x9a\x01\n\nUpdateInfo\x12\x20\n\nminVersion\x18\
+    \x01\x20\x01(\rR\nminVersionB\0\x12&\n\rlatestVersion\x18\x02\x20\x01(\r\
+    R\rlatestVersionB\0\x12\"\n\x0bforceUpdate\x18\x03\x20\x01(\x08R\x0bforc\
+    eUpdateB\0\x12\x1c\n\x08storeUrl\x18\x04\x20\x01(\tR\x08storeUrlB\0:\0\"\
+    ?\n\x0fUserCredentials\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\
+    \x18\n\x06secret\x18\x02\x20\x01(\tR\x06secretB\0:\0\"\x85\x01\n\x10Regi\
+    sterResponse\x12&\n\x04user\x18\x01\x20\x01(\x0b2\x10.UserCredentialsR\
+    \x04userB\0\x12\x20\n\nisOutdated\x18\x02\x20\x01(\x08R\nisOutdatedB\0\
+    \x12%\n\x06update\x18\x03\x20\x01(\x0b2\x0b.UpdateInfoR\x06updateB\0:\0\
+    \"\xc4\x01\n\x11RejectionResponse\x12c\n\x16sessionFilterRejection\x18\
+    \x01\x20\x01(\x0e2).RejectionResponse.SessionFilterRejectionR\x16session\
+    FilterRejectionB\0\"H\n\x16SessionFilterRejection\x12\x08\n\x04NONE\x10\
+    \0\x12\x0b\n\x07INVALID\x10\x01\x12\x15\n\x11SESSION_NOT_FOUND\x10\x02\
+    \x1a\0:\0\"6\n\x16RevokeSessionsResponse\x12\x1a\n\x07revoked\x18\x01\
+    \x20\x01(\rR\x07revokedB\0:\0\"5\n\x13RestoreUserResponse\x12\x1c\n\x08r\
+    estored\x18\x01\x20\x01(\x08R\x08restoredB\0:\0\"\xca\x01\n\x11AdminUser\
+    Response\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x1a\n\x07versi\
+    on\x18\x02\x20\x01(\x04R\x07versionB\0\x12\x1a\n\x07country\x18\x03\x20\
+    \x01(\tR\x07countryB\0\x12\x1c\n\x08language\x18\x04\x20\x01(\tR\x08lang\
+    uageB\0\x12\x1e\n\tdeletedAt\x18\x05\x20\x01(\x03R\tdeletedAtB\0\x12+\n\
+    \x08sessions\x18\x06\x20\x03(\x0b2\r.AdminSessionR\x08sessionsB\0:\0\"v\
+    \n\x0cAdminSession\x12\x10\n\x02id\x18\x01\x20\x01(\tR\x02idB\0\x12\x16\
+    \n\x05valid\x18\x02\x20\x01(\x08R\x05validB\0\x12\x1c\n\x08deviceId\x18\
+    \x03\x20\x01(\tR\x08deviceIdB\0\x12\x1c\n\x08platform\x18\x04\x20\x01(\t\
+    R\x08platformB\0:\0B\x04\xb0\xa8\x08\x01b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,499 @@
+use super::{sessions::Session, user::User, UserLoginResource};
+use crate::{
+	error, pbwarp, rejection::AdminFailure, schema, CustomModule,
+	ModuleResources,
+};
+use frunk::Hlist;
+use std::{convert::TryFrom, sync::Arc};
+use subtle::ConstantTimeEq;
+use warp::{
+	filters::BoxedFilter, hyper::header::CONTENT_TYPE, Filter,
+	Rejection, Reply,
+};
+
+pub const HEADER_ADMIN_KEY: &str = "X-GR-Admin-Key";
+
+/// admin/support endpoints for user management,
+/// requires `UserLoginResource::set_admin_key`
+pub struct UserLoginAdmin {}
+
+impl CustomModule for UserLoginAdmin {
+	type Resources = Hlist![Arc<UserLoginResource>];
+
+	fn create_filter<S: ModuleResources<Self>>(
+		server: std::sync::Arc<S>,
+	) -> warp::filters::BoxedFilter<(Box<dyn warp::Reply>,)> {
+		let (resource, _) = server.get_server_resources().pluck();
+
+		let userlogin = {
+			let resource = resource.clone();
+			warp::any().map(move || resource.clone())
+		};
+
+		let revoke_sessions_filter = warp::path!(
+			"admin" / "user" / String / "revoke_sessions"
+		)
+		.and(warp::post())
+		.and(admin_filter(resource.clone()))
+		.and(userlogin.clone())
+		.and(warp::header::optional::<String>(CONTENT_TYPE.as_str()))
+		.and_then(revoke_sessions_fn);
+
+		let restore_filter =
+			warp::path!("admin" / "user" / String / "restore")
+				.and(warp::post())
+				.and(admin_filter(resource.clone()))
+				.and(userlogin.clone())
+				.and(warp::header::optional::<String>(
+					CONTENT_TYPE.as_str(),
+				))
+				.and_then(restore_fn);
+
+		let user_filter = warp::path!("admin" / "user" / String)
+			.and(warp::get())
+			.and(admin_filter(resource.clone()))
+			.and(userlogin.clone())
+			.and(warp::header::optional::<String>(
+				CONTENT_TYPE.as_str(),
+			))
+			.and_then(user_fn);
+
+		let session_user_filter =
+			warp::path!("admin" / "session" / String / "user")
+				.and(warp::get())
+				.and(admin_filter(resource))
+				.and(userlogin)
+				.and(warp::header::optional::<String>(
+					CONTENT_TYPE.as_str(),
+				))
+				.and_then(session_user_fn);
+
+		let filters: BoxedFilter<(Box<dyn Reply>,)> =
+			revoke_sessions_filter
+				.or(restore_filter)
+				.or(user_filter)
+				.or(session_user_filter)
+				.map(move |reply| -> Box<dyn Reply> {
+					Box::new(reply)
+				})
+				.boxed();
+
+		filters
+	}
+}
+
+/// Returns filter that rejects requests without the configured admin key
+///
+/// Intended to be used for composing warp filters
+#[must_use]
+pub fn admin_filter(
+	resource: Arc<UserLoginResource>,
+) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+	warp::header::optional::<String>(HEADER_ADMIN_KEY)
+		.and_then(move |key: Option<String>| {
+			let resource = resource.clone();
+			async move {
+				match (key, resource.admin_key.as_ref()) {
+					(Some(key), Some(admin_key))
+						if bool::from(
+							key.as_bytes()
+								.ct_eq(admin_key.as_bytes()),
+						) =>
+					{
+						Ok(())
+					}
+					_ => Err(warp::reject::custom(AdminFailure)),
+				}
+			}
+		})
+		.untuple_one()
+}
+
+async fn revoke_sessions_fn(
+	user_id: String,
+	resource: Arc<UserLoginResource>,
+	content_type: Option<String>,
+) -> Result<impl warp::Reply, Rejection> {
+	match resource.revoke_sessions(&user_id).await {
+		Ok(count) => {
+			let response = schema::RevokeSessionsResponse {
+				revoked: u32::try_from(count).unwrap_or(u32::MAX),
+				..schema::RevokeSessionsResponse::default()
+			};
+
+			Ok(pbwarp::protobuf_reply(&response, content_type)
+				.into_response())
+		}
+		Err(err) => {
+			tracing::error!("{}", err);
+
+			Ok(warp::reply::with_status(
+				String::from("failed to revoke sessions"),
+				warp::hyper::StatusCode::BAD_REQUEST,
+			)
+			.into_response())
+		}
+	}
+}
+
+async fn restore_fn(
+	user_id: String,
+	resource: Arc<UserLoginResource>,
+	content_type: Option<String>,
+) -> Result<impl warp::Reply, Rejection> {
+	match resource.restore_user(&user_id).await {
+		Ok(restored) => {
+			let response = schema::RestoreUserResponse {
+				restored,
+				..schema::RestoreUserResponse::default()
+			};
+
+			Ok(pbwarp::protobuf_reply(&response, content_type)
+				.into_response())
+		}
+		Err(err) => {
+			tracing::error!("{}", err);
+
+			Ok(warp::reply::with_status(
+				String::from("failed to restore user"),
+				warp::hyper::StatusCode::BAD_REQUEST,
+			)
+			.into_response())
+		}
+	}
+}
+
+async fn user_fn(
+	user_id: String,
+	resource: Arc<UserLoginResource>,
+	content_type: Option<String>,
+) -> Result<impl warp::Reply, Rejection> {
+	Ok(user_reply(
+		resource.admin_user(&user_id).await,
+		content_type,
+	))
+}
+
+async fn session_user_fn(
+	session: String,
+	resource: Arc<UserLoginResource>,
+	content_type: Option<String>,
+) -> Result<impl warp::Reply, Rejection> {
+	let user = match resource.sessions.peek(&session).await {
+		Some(session) => resource.admin_user(&session.user_id).await,
+		None => Ok(None),
+	};
+
+	Ok(user_reply(user, content_type))
+}
+
+fn user_reply(
+	user: error::Result<Option<schema::AdminUserResponse>>,
+	content_type: Option<String>,
+) -> warp::reply::Response {
+	match user {
+		Ok(Some(response)) => {
+			pbwarp::protobuf_reply(&response, content_type)
+				.into_response()
+		}
+		Ok(None) => warp::reply::with_status(
+			String::from("user not found"),
+			warp::hyper::StatusCode::NOT_FOUND,
+		)
+		.into_response(),
+		Err(err) => {
+			tracing::error!("{}", err);
+
+			warp::reply::with_status(
+				String::from("failed to get user"),
+				warp::hyper::StatusCode::BAD_REQUEST,
+			)
+			.into_response()
+		}
+	}
+}
+
+impl UserLoginResource {
+	/// user including its sessions for support, `None` if the user is unknown
+	async fn admin_user(
+		&self,
+		user_id: &str,
+	) -> error::Result<Option<schema::AdminUserResponse>> {
+		let Some(user) = self.users.get_user(user_id).await else {
+			return Ok(None);
+		};
+
+		let sessions = self.sessions.list_for_user(user_id).await?;
+
+		Ok(Some(admin_user_response(user, sessions)))
+	}
+}
+
+fn admin_user_response(
+	user: User,
+	sessions: Vec<(String, Session)>,
+) -> schema::AdminUserResponse {
+	schema::AdminUserResponse {
+		id: user.id,
+		version: user.version,
+		country: user.country.unwrap_or_default(),
+		language: user.language.unwrap_or_default(),
+		deletedAt: user.deleted_at.unwrap_or_default(),
+		sessions: sessions
+			.into_iter()
+			.map(|(id, session)| schema::AdminSession {
+				id,
+				valid: session.valid,
+				deviceId: session.device_id.unwrap_or_default(),
+				platform: session.platform.unwrap_or_default(),
+				..schema::AdminSession::default()
+			})
+			.collect(),
+		..schema::AdminUserResponse::default()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		rejection::handle_rejection,
+		userlogin::{
+			sessions::{InMemorySessionDB, Session, SessionDB},
+			user::{in_memory::InMemoryUserDB, User, UserDB},
+		},
+		CustomServer, Module,
+	};
+	use frunk::hlist;
+	use protobuf::Message;
+	use warp::hyper::StatusCode;
+
+	pub struct AdminServer {
+		resources: Hlist![Arc<UserLoginResource>],
+	}
+
+	impl CustomServer for AdminServer {
+		type Resources = Hlist![Arc<UserLoginResource>];
+
+		const MODULES: &'static [Module<Self>] = &[Module {
+			name: "userlogin_admin",
+			call: UserLoginAdmin::create_filter,
+		}];
+
+		fn get_resources(&self) -> &Self::Resources {
+			&self.resources
+		}
+	}
+
+	impl ModuleResources<UserLoginAdmin> for AdminServer {
+		fn get_server_resources(
+			&self,
+		) -> <UserLoginAdmin as CustomModule>::Resources {
+			let (resources, _) =
+				self.get_resources().clone().sculpt();
+			resources
+		}
+	}
+
+	fn server(
+		sessions: Arc<InMemorySessionDB>,
+		admin_key: Option<&str>,
+	) -> Arc<AdminServer> {
+		server_with_users(
+			sessions,
+			Arc::new(InMemoryUserDB::default()),
+			admin_key,
+		)
+	}
+
+	fn server_with_users(
+		sessions: Arc<InMemorySessionDB>,
+		users: Arc<InMemoryUserDB>,
+		admin_key: Option<&str>,
+	) -> Arc<AdminServer> {
+		let mut resource = UserLoginResource::new(sessions, users);
+		if let Some(key) = admin_key {
+			resource.set_admin_key(key.to_string());
+		}
+
+		Arc::new(AdminServer {
+			resources: hlist![Arc::new(resource)],
+		})
+	}
+
+	#[tokio::test]
+	async fn test_revoke_sessions() {
+		let sessions = Arc::new(InMemorySessionDB::default());
+		let s1 = sessions.create(Session::new("uid")).await.unwrap();
+		let s2 = sessions.create(Session::new("uid")).await.unwrap();
+		let other =
+			sessions.create(Session::new("other")).await.unwrap();
+
+		let filter = UserLoginAdmin::create_filter(server(
+			sessions.clone(),
+			Some("key"),
+		))
+		.recover(handle_rejection);
+
+		let reply = warp::test::request()
+			.method("POST")
+			.header(HEADER_ADMIN_KEY, "key")
+			.path("/admin/user/uid/revoke_sessions")
+			.reply(&filter)
+			.await;
+
+		assert_eq!(reply.status(), StatusCode::OK);
+		let response =
+			schema::RevokeSessionsResponse::parse_from_bytes(
+				reply.body(),
+			)
+			.unwrap();
+		assert_eq!(response.revoked, 2);
+
+		assert!(!sessions.get(&s1).await.unwrap().valid);
+		assert!(!sessions.get(&s2).await.unwrap().valid);
+		assert!(sessions.get(&other).await.unwrap().valid);
+	}
+
+	#[tokio::test]
+	async fn test_restore_user() {
+		let users = Arc::new(InMemoryUserDB::default());
+		let user = User {
+			deleted_at: Some(100),
+			..User::new(None, None)
+		};
+		users.save_user(&user).await.unwrap();
+
+		let filter =
+			UserLoginAdmin::create_filter(server_with_users(
+				Arc::new(InMemorySessionDB::default()),
+				users.clone(),
+				Some("key"),
+			))
+			.recover(handle_rejection);
+
+		let restore = || {
+			warp::test::request()
+				.method("POST")
+				.header(HEADER_ADMIN_KEY, "key")
+				.path(&format!("/admin/user/{}/restore", user.id))
+				.reply(&filter)
+		};
+
+		let reply = restore().await;
+		assert_eq!(reply.status(), StatusCode::OK);
+		let response = schema::RestoreUserResponse::parse_from_bytes(
+			reply.body(),
+		)
+		.unwrap();
+		assert!(response.restored);
+		assert!(!users
+			.get_user(&user.id)
+			.await
+			.unwrap()
+			.is_deleted());
+
+		let reply = restore().await;
+		let response = schema::RestoreUserResponse::parse_from_bytes(
+			reply.body(),
+		)
+		.unwrap();
+		assert!(!response.restored);
+
+		let reply = warp::test::request()
+			.method("POST")
+			.header(HEADER_ADMIN_KEY, "key")
+			.path("/admin/user/unknown/restore")
+			.reply(&filter)
+			.await;
+		assert_eq!(reply.status(), StatusCode::BAD_REQUEST);
+	}
+
+	#[tokio::test]
+	async fn test_user_lookup() {
+		let sessions = Arc::new(InMemorySessionDB::default());
+		let users = Arc::new(InMemoryUserDB::default());
+		let user = User::new(Some("DE".into()), None);
+		users.save_user(&user).await.unwrap();
+		let session = sessions
+			.create(Session::new(&user.id).with_device(
+				Some("device".into()),
+				Some("ios".into()),
+			))
+			.await
+			.unwrap();
+
+		let filter = UserLoginAdmin::create_filter(
+			server_with_users(sessions, users, Some("key")),
+		)
+		.recover(handle_rejection);
+
+		for path in [
+			format!("/admin/user/{}", user.id),
+			format!("/admin/session/{session}/user"),
+		] {
+			let reply = warp::test::request()
+				.header(HEADER_ADMIN_KEY, "key")
+				.path(&path)
+				.reply(&filter)
+				.await;
+
+			assert_eq!(reply.status(), StatusCode::OK);
+			let response =
+				schema::AdminUserResponse::parse_from_bytes(
+					reply.body(),
+				)
+				.unwrap();
+			assert_eq!(response.id, user.id);
+			assert_eq!(response.country, "DE");
+			assert_eq!(response.sessions.len(), 1);
+			assert_eq!(response.sessions[0].id, session);
+			assert!(response.sessions[0].valid);
+			assert_eq!(response.sessions[0].platform, "ios");
+		}
+
+		for path in
+			["/admin/user/unknown", "/admin/session/unknown/user"]
+		{
+			let reply = warp::test::request()
+				.header(HEADER_ADMIN_KEY, "key")
+				.path(path)
+				.reply(&filter)
+				.await;
+
+			assert_eq!(reply.status(), StatusCode::NOT_FOUND);
+		}
+	}
+
+	#[tokio::test]
+	async fn test_admin_key_rejected() {
+		let sessions = Arc::new(InMemorySessionDB::default());
+		let session =
+			sessions.create(Session::new("uid")).await.unwrap();
+
+		for (configured, sent) in [
+			(Some("key"), Some("wrong")),
+			(Some("key"), None),
+			(None, None),
+		] {
+			let filter = UserLoginAdmin::create_filter(server(
+				sessions.clone(),
+				configured,
+			))
+			.recover(handle_rejection);
+
+			let mut request = warp::test::request()
+				.method("POST")
+				.path("/admin/user/uid/revoke_sessions");
+			if let Some(sent) = sent {
+				request = request.header(HEADER_ADMIN_KEY, sent);
+			}
+
+			let reply = request.reply(&filter).await;
+
+			assert_eq!(reply.status(), StatusCode::UNAUTHORIZED);
+		}
+
+		assert!(sessions.get(&session).await.unwrap().valid);
+	}
+}
@@ -0,0 +1,218 @@
+use warp::hyper::{
+	header::{COOKIE, ORIGIN, REFERER},
+	HeaderMap, Uri,
+};
+
+/// `SameSite` attribute of the session cookie
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SameSite {
+	Strict,
+	Lax,
+	/// requires `secure`, needed if the game is embedded on another site,
+	/// list that site in [`SessionCookie::allowed_origins`]
+	None,
+}
+
+impl SameSite {
+	const fn as_str(self) -> &'static str {
+		match self {
+			Self::Strict => "Strict",
+			Self::Lax => "Lax",
+			Self::None => "None",
+		}
+	}
+}
+
+/// `HttpOnly` cookie the session is additionally set/read as,
+/// intended for browser based clients served from the same site
+#[derive(Debug, Clone)]
+pub struct SessionCookie {
+	pub name: String,
+	pub path: String,
+	/// only send via https
+	pub secure: bool,
+	pub same_site: SameSite,
+	/// origins (e.g. `https://game.example.com`) the cookie is accepted
+	/// from, checked against the `Origin` (or `Referer`) header of a request
+	///
+	/// browsers attach the cookie to requests of any other site too,
+	/// so requests from elsewhere (or without either header) are
+	/// rejected as without session to protect against CSRF
+	pub allowed_origins: Vec<String>,
+}
+
+impl Default for SessionCookie {
+	fn default() -> Self {
+		Self {
+			name: String::from("gr_session"),
+			path: String::from("/"),
+			secure: true,
+			same_site: SameSite::Lax,
+			allowed_origins: Vec::new(),
+		}
+	}
+}
+
+impl SessionCookie {
+	/// `Set-Cookie` value for `session`
+	#[must_use]
+	pub fn set(&self, session: &str) -> String {
+		let mut cookie = format!(
+			"{}={}; Path={}; HttpOnly; SameSite={}",
+			self.name,
+			session,
+			self.path,
+			self.same_site.as_str()
+		);
+
+		if self.secure {
+			cookie.push_str("; Secure");
+		}
+
+		cookie
+	}
+
+	/// `Set-Cookie` value removing the cookie on the client
+	#[must_use]
+	pub fn clear(&self) -> String {
+		format!("{}; Max-Age=0", self.set(""))
+	}
+
+	/// session from the `Cookie` header(s) of a request,
+	/// `None` if it is not sent from one of `allowed_origins`
+	#[must_use]
+	pub fn get(&self, headers: &HeaderMap) -> Option<String> {
+		if !self.is_allowed_origin(headers) {
+			return None;
+		}
+
+		headers
+			.get_all(COOKIE)
+			.iter()
+			.filter_map(|value| value.to_str().ok())
+			.flat_map(|value| value.split(';'))
+			.filter_map(|pair| pair.trim().split_once('='))
+			.find(|(name, _)| *name == self.name)
+			.map(|(_, value)| value.to_string())
+			.filter(|value| !value.is_empty())
+	}
+
+	fn is_allowed_origin(&self, headers: &HeaderMap) -> bool {
+		let origin = headers
+			.get(ORIGIN)
+			.and_then(|value| value.to_str().ok())
+			.map(String::from)
+			.or_else(|| {
+				let referer = headers
+					.get(REFERER)?
+					.to_str()
+					.ok()?
+					.parse::<Uri>()
+					.ok()?;
+				Some(format!(
+					"{}://{}",
+					referer.scheme_str()?,
+					referer.authority()?
+				))
+			});
+
+		origin.is_some_and(|origin| {
+			self.allowed_origins
+				.iter()
+				.any(|allowed| allowed.eq_ignore_ascii_case(&origin))
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use warp::hyper::header::HeaderValue;
+
+	#[test]
+	fn test_set_cookie() {
+		let cookie = SessionCookie::default();
+
+		assert_eq!(
+			cookie.set("sid"),
+			"gr_session=sid; Path=/; HttpOnly; SameSite=Lax; Secure"
+		);
+
+		let cookie = SessionCookie {
+			secure: false,
+			same_site: SameSite::Strict,
+			..SessionCookie::default()
+		};
+
+		assert_eq!(
+			cookie.clear(),
+			"gr_session=; Path=/; HttpOnly; SameSite=Strict; Max-Age=0"
+		);
+	}
+
+	fn cookie() -> SessionCookie {
+		SessionCookie {
+			allowed_origins: vec!["https://game.example.com".into()],
+			..SessionCookie::default()
+		}
+	}
+
+	#[test]
+	fn test_get_cookie() {
+		let cookie = cookie();
+		let mut headers = HeaderMap::new();
+		headers.insert(
+			ORIGIN,
+			HeaderValue::from_static("https://game.example.com"),
+		);
+
+		assert_eq!(cookie.get(&headers), None);
+
+		headers.append(COOKIE, HeaderValue::from_static("a=b"));
+		headers.append(
+			COOKIE,
+			HeaderValue::from_static("c=d; gr_session=sid; e=f"),
+		);
+
+		assert_eq!(cookie.get(&headers), Some("sid".to_string()));
+
+		headers.remove(COOKIE);
+		headers
+			.append(COOKIE, HeaderValue::from_static("gr_session="));
+
+		assert_eq!(cookie.get(&headers), None);
+	}
+
+	#[test]
+	fn test_cookie_origin() {
+		let cookie = cookie();
+		let mut headers = HeaderMap::new();
+		headers.append(
+			COOKIE,
+			HeaderValue::from_static("gr_session=sid"),
+		);
+
+		// neither `Origin` nor `Referer`
+		assert_eq!(cookie.get(&headers), None);
+
+		headers.insert(
+			REFERER,
+			HeaderValue::from_static("https://game.example.com/play"),
+		);
+		assert_eq!(cookie.get(&headers), Some("sid".to_string()));
+
+		headers.insert(
+			ORIGIN,
+			HeaderValue::from_static("https://evil.example.com"),
+		);
+		assert_eq!(cookie.get(&headers), None);
+
+		headers.insert(
+			ORIGIN,
+			HeaderValue::from_static("https://GAME.example.com"),
+		);
+		assert_eq!(cookie.get(&headers), Some("sid".to_string()));
+
+		assert_eq!(SessionCookie::default().get(&headers), None);
+	}
+}
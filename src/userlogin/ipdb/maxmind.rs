@@ -0,0 +1,396 @@
+use super::IpLookup;
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use flate2::read::GzDecoder;
+use hyper_tls::HttpsConnector;
+use maxminddb::{geoip2, Reader};
+use std::{
+	io::Read,
+	net::IpAddr,
+	path::Path,
+	sync::{Arc, RwLock},
+	time::Duration,
+};
+use tokio::task::JoinHandle;
+use warp::hyper::{body, header::LOCATION, Client, Response, Uri};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// the license key download redirects to the actual storage
+const MAX_REDIRECTS: usize = 5;
+
+/// where to (re-)download the database from
+#[derive(Debug, Clone)]
+pub enum MaxMindSource {
+	/// url serving either a plain `.mmdb` or a `.tar.gz` containing one
+	Url(String),
+	/// official maxmind download using a license key, e.g. `GeoLite2-Country` as `edition_id`
+	LicenseKey {
+		license_key: String,
+		edition_id: String,
+	},
+}
+
+impl MaxMindSource {
+	fn url(&self) -> String {
+		match self {
+			Self::Url(url) => url.clone(),
+			Self::LicenseKey {
+				license_key,
+				edition_id,
+			} => format!(
+				"https://download.maxmind.com/app/geoip_download?edition_id={edition_id}&license_key={license_key}&suffix=tar.gz"
+			),
+		}
+	}
+
+	#[allow(clippy::result_large_err)]
+	async fn download(&self) -> Result<Vec<u8>> {
+		let mut uri = self
+			.url()
+			.parse::<Uri>()
+			.map_err(|e| Error::Custom(e.to_string()))?;
+
+		let client = Client::builder()
+			.build::<_, body::Body>(HttpsConnector::new());
+
+		let mut redirects = 0;
+		let resp = loop {
+			let resp = client.get(uri.clone()).await?;
+
+			if !resp.status().is_redirection() {
+				break resp;
+			}
+
+			redirects += 1;
+			if redirects > MAX_REDIRECTS {
+				return Err(Error::Custom(String::from(
+					"maxmind download failed: too many redirects",
+				)));
+			}
+
+			uri = redirect_uri(&uri, &resp)?;
+		};
+
+		if !resp.status().is_success() {
+			return Err(Error::Custom(format!(
+				"maxmind download failed: {}",
+				resp.status()
+			)));
+		}
+
+		let bytes = body::to_bytes(resp).await?.to_vec();
+
+		if bytes.starts_with(&GZIP_MAGIC) {
+			tokio::task::spawn_blocking(move || extract_mmdb(&bytes))
+				.await
+				.map_err(|e| Error::Custom(e.to_string()))?
+		} else {
+			Ok(bytes)
+		}
+	}
+}
+
+/// resolves the `Location` of a redirect response, relative to `uri`
+#[allow(clippy::result_large_err)]
+fn redirect_uri(
+	uri: &Uri,
+	resp: &Response<body::Body>,
+) -> Result<Uri> {
+	let location = resp
+		.headers()
+		.get(LOCATION)
+		.and_then(|location| location.to_str().ok())
+		.ok_or_else(|| {
+			Error::Custom(format!(
+				"maxmind download failed: {} without location",
+				resp.status()
+			))
+		})?
+		.parse::<Uri>()
+		.map_err(|e| Error::Custom(e.to_string()))?;
+
+	if location.scheme().is_some() {
+		return Ok(location);
+	}
+
+	let mut parts = location.into_parts();
+	parts.scheme = uri.scheme().cloned();
+	parts.authority = uri.authority().cloned();
+
+	Uri::from_parts(parts).map_err(|e| Error::Custom(e.to_string()))
+}
+
+/// returns the first `.mmdb` file found in a `.tar.gz` archive
+#[allow(clippy::result_large_err)]
+fn extract_mmdb(archive: &[u8]) -> Result<Vec<u8>> {
+	let mut archive = tar::Archive::new(GzDecoder::new(archive));
+
+	for entry in archive.entries()? {
+		let mut entry = entry?;
+		let is_mmdb = entry
+			.path()?
+			.extension()
+			.is_some_and(|ext| ext == "mmdb");
+
+		if is_mmdb {
+			let mut buf = Vec::new();
+			entry.read_to_end(&mut buf)?;
+			return Ok(buf);
+		}
+	}
+
+	Err(Error::Custom(String::from("no .mmdb file in archive")))
+}
+
+/// offline lookup in a GeoLite2/GeoIP2 country (or city) `.mmdb` database,
+/// clones share the same database so a refresh is visible to all of them
+#[derive(Clone)]
+pub struct MaxMindIpDB {
+	reader: Arc<RwLock<Arc<Reader<Vec<u8>>>>>,
+}
+
+//TODO: box the large rusoto variants of `Error`
+#[allow(clippy::result_large_err)]
+impl MaxMindIpDB {
+	/// loads the database file into memory
+	///
+	/// # Errors
+	///
+	/// fails if the file cannot be read or is no valid maxmind db
+	pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+		Ok(Self::from_reader(Reader::open_readfile(path)?))
+	}
+
+	/// # Errors
+	///
+	/// fails if `bytes` is no valid maxmind db
+	pub fn from_bytes(bytes: Vec<u8>) -> Result<Self> {
+		Ok(Self::from_reader(Reader::from_source(bytes)?))
+	}
+
+	/// downloads the database from `source`
+	///
+	/// # Errors
+	///
+	/// fails if the download fails or it is no valid maxmind db
+	pub async fn download(source: &MaxMindSource) -> Result<Self> {
+		Self::from_bytes(source.download().await?)
+	}
+
+	fn from_reader(reader: Reader<Vec<u8>>) -> Self {
+		Self {
+			reader: Arc::new(RwLock::new(Arc::new(reader))),
+		}
+	}
+
+	/// atomically replaces the database, lookups in flight finish on the old one
+	///
+	/// # Errors
+	///
+	/// fails if `bytes` is no valid maxmind db, the current db is kept then
+	pub fn swap(&self, bytes: Vec<u8>) -> Result<()> {
+		let reader = Arc::new(Reader::from_source(bytes)?);
+
+		*self
+			.reader
+			.write()
+			.map_err(|e| Error::Custom(e.to_string()))? = reader;
+
+		Ok(())
+	}
+
+	/// re-downloads the database from `source` and swaps it in
+	///
+	/// # Errors
+	///
+	/// fails if the download fails or it is no valid maxmind db
+	pub async fn refresh(
+		&self,
+		source: &MaxMindSource,
+	) -> Result<()> {
+		self.swap(source.download().await?)?;
+
+		tracing::info!("maxmind db refreshed");
+
+		Ok(())
+	}
+
+	/// spawns a task refreshing the database from `source` every `interval`,
+	/// failed refreshes are logged and the current database is kept
+	#[must_use]
+	pub fn spawn_refresh(
+		&self,
+		source: MaxMindSource,
+		interval: Duration,
+	) -> JoinHandle<()> {
+		let db = self.clone();
+
+		tokio::spawn(async move {
+			let mut interval = tokio::time::interval(interval);
+			// first tick completes immediately, the db was just loaded
+			interval.tick().await;
+
+			loop {
+				interval.tick().await;
+
+				if let Err(e) = db.refresh(&source).await {
+					tracing::error!(
+						"maxmind db refresh error: {}",
+						e
+					);
+				}
+			}
+		})
+	}
+
+	/// `ip` may be a `X-Forwarded-For` list (`client, proxy1, ...`)
+	fn country(&self, ip: &str) -> Option<String> {
+		let ip =
+			ip.split(',').next()?.trim().parse::<IpAddr>().ok()?;
+
+		let reader = self
+			.reader
+			.read()
+			.map_err(|e| tracing::error!("maxmind db lock: {}", e))
+			.ok()?
+			.clone();
+
+		let country: geoip2::Country = reader
+			.lookup(ip)
+			.map_err(|e| tracing::debug!("ip lookup error: {}", e))
+			.ok()?;
+
+		country
+			.country
+			.and_then(|country| country.iso_code)
+			.map(String::from)
+	}
+}
+
+#[async_trait]
+impl IpLookup for MaxMindIpDB {
+	async fn lookup(&self, ip: &str) -> Option<String> {
+		self.country(ip)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use flate2::{write::GzEncoder, Compression};
+
+	/// minimal GeoIP2 country db with `81.2.69.160/27` (GB)
+	/// and `216.160.83.56/29` (US)
+	const TEST_DB: &str = concat!(
+		env!("CARGO_MANIFEST_DIR"),
+		"/tests/data/Country-Test.mmdb"
+	);
+
+	#[tokio::test]
+	async fn test_lookup() {
+		let db = MaxMindIpDB::open(TEST_DB).unwrap();
+
+		assert_eq!(db.lookup("81.2.69.160").await, Some("GB".into()));
+		assert_eq!(db.lookup("81.2.69.191").await, Some("GB".into()));
+		assert_eq!(
+			db.lookup("216.160.83.60").await,
+			Some("US".into())
+		);
+		assert_eq!(
+			db.lookup("81.2.69.160, 10.0.0.1").await,
+			Some("GB".into())
+		);
+		assert_eq!(db.lookup("81.2.69.192").await, None);
+		assert_eq!(db.lookup("invalid").await, None);
+	}
+
+	#[test]
+	fn test_invalid_db() {
+		assert!(MaxMindIpDB::open("does/not/exist.mmdb").is_err());
+		assert!(MaxMindIpDB::from_bytes(vec![0; 32]).is_err());
+	}
+
+	fn archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+		let mut builder = tar::Builder::new(GzEncoder::new(
+			Vec::new(),
+			Compression::default(),
+		));
+
+		for (path, data) in files {
+			let mut header = tar::Header::new_gnu();
+			header.set_size(data.len() as u64);
+			header.set_cksum();
+			builder.append_data(&mut header, path, *data).unwrap();
+		}
+
+		builder.into_inner().unwrap().finish().unwrap()
+	}
+
+	#[test]
+	fn test_extract_mmdb() {
+		let archive = archive(&[
+			("GeoLite2-Country_20220301/LICENSE.txt", b"license"),
+			(
+				"GeoLite2-Country_20220301/GeoLite2-Country.mmdb",
+				b"db",
+			),
+		]);
+
+		assert!(archive.starts_with(&GZIP_MAGIC));
+		assert_eq!(extract_mmdb(&archive).unwrap(), b"db");
+	}
+
+	#[test]
+	fn test_extract_mmdb_missing() {
+		let archive = archive(&[("LICENSE.txt", b"license")]);
+
+		assert!(extract_mmdb(&archive).is_err());
+	}
+
+	#[tokio::test]
+	async fn test_download_redirect() {
+		let _redirect = mockito::mock("GET", "/maxmind/redirect")
+			.with_status(302)
+			.with_header("Location", "/maxmind/db.tar.gz")
+			.create();
+		let _db = mockito::mock("GET", "/maxmind/db.tar.gz")
+			.with_body(archive(&[("GeoLite2-Country.mmdb", b"db")]))
+			.create();
+
+		let source = MaxMindSource::Url(format!(
+			"{}/maxmind/redirect",
+			mockito::server_url()
+		));
+
+		assert_eq!(source.download().await.unwrap(), b"db");
+	}
+
+	#[tokio::test]
+	async fn test_download_redirect_loop() {
+		let _redirect = mockito::mock("GET", "/maxmind/loop")
+			.with_status(302)
+			.with_header("Location", "/maxmind/loop")
+			.create();
+
+		let source = MaxMindSource::Url(format!(
+			"{}/maxmind/loop",
+			mockito::server_url()
+		));
+
+		assert!(source.download().await.is_err());
+	}
+
+	#[test]
+	fn test_license_key_url() {
+		let source = MaxMindSource::LicenseKey {
+			license_key: String::from("key"),
+			edition_id: String::from("GeoLite2-Country"),
+		};
+
+		assert_eq!(
+			source.url(),
+			"https://download.maxmind.com/app/geoip_download?edition_id=GeoLite2-Country&license_key=key&suffix=tar.gz"
+		);
+	}
+}
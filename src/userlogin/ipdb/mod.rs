@@ -1,5 +1,20 @@
+#[cfg(feature = "maxminddb")]
+mod maxmind;
+
+#[cfg(feature = "maxminddb")]
+pub use maxmind::{MaxMindIpDB, MaxMindSource};
+
+use async_trait::async_trait;
 use warp::hyper::{body, Client, Uri};
 
+/// resolves the two letter iso country code of an ip address,
+/// implement this to plug in a custom geo provider
+#[async_trait]
+pub trait IpLookup: Send + Sync {
+	async fn lookup(&self, ip: &str) -> Option<String>;
+}
+
+/// remote lookup via a http service returning the country code for `GET {url}/{ip}`
 #[derive(Debug, Clone, Default)]
 pub struct IpDB {
 	url: String,
@@ -32,3 +47,10 @@ impl IpDB {
 		None
 	}
 }
+
+#[async_trait]
+impl IpLookup for IpDB {
+	async fn lookup(&self, ip: &str) -> Option<String> {
+		Self::lookup(self, ip).await
+	}
+}
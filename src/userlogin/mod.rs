@@ -1,27 +1,45 @@
+pub mod admin;
+pub mod cookie;
 pub mod ipdb;
 pub mod sessions;
 pub mod user;
+pub mod version;
 
 use crate::{
 	error, pbwarp, rejection::SessionFailure, schema, CustomModule,
 	ModuleResources,
 };
 use async_trait::async_trait;
+use cookie::SessionCookie;
 use frunk::Hlist;
-use ipdb::IpDB;
+use ipdb::IpLookup;
 use sessions::Session;
-use std::{net::SocketAddr, sync::Arc};
+use std::{
+	convert::Infallible, net::SocketAddr, sync::Arc, time::Duration,
+};
+use tokio::task::JoinHandle;
 use tracing::instrument;
 use user::{User, UserDB};
+use version::VersionConfig;
 use warp::{
-	filters::BoxedFilter, hyper::header::CONTENT_TYPE, Filter,
-	Rejection, Reply,
+	filters::BoxedFilter,
+	hyper::{
+		header::{CONTENT_TYPE, SET_COOKIE},
+		HeaderMap,
+	},
+	Filter, Rejection, Reply,
 };
 
-//TODO: make configurable from using crate
+/// default minimum client version, see [`UserLoginResource::set_version_config`]
 pub const MIN_CLIENT_VERSION: u32 = 1;
 
-//TODO: this shouldn't be defined here
+/// default time soft deleted users can be restored before they get purged
+// `Duration::from_hours` needs rust 1.91
+#[allow(clippy::duration_suboptimal_units)]
+pub const DELETION_GRACE_PERIOD: Duration =
+	Duration::from_secs(30 * 24 * 60 * 60);
+
+/// default session header, see [`UserLoginResource::set_session_header`]
 pub const HEADER_SESSION: &str = "X-GR-Session";
 
 //TODO: use everywhere
@@ -37,25 +55,67 @@ pub enum SessionValidationResult {
 	Unknown,
 }
 
+/// user info resolved from the session alone, see [`session_user_filter`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionContext {
+	pub user_id: UserId,
+	pub country: Option<String>,
+	pub language: Option<String>,
+	pub platform: Option<String>,
+}
+
+impl From<Session> for SessionContext {
+	fn from(session: Session) -> Self {
+		Self {
+			user_id: session.user_id,
+			country: session.country,
+			language: session.language,
+			platform: session.platform,
+		}
+	}
+}
+
 pub struct UserLogin {}
 
 #[async_trait]
 pub trait UserLoginEvents: Send + Sync {
+	/// `session` is the newly created session incl. device info
 	async fn on_login(
 		&self,
 		_user: &User,
+		_session: &Session,
 	) -> Result<(), error::Error>;
+	/// `session` is the newly created session incl. device info
 	async fn on_register(
 		&self,
 		_user: &User,
+		_session: &Session,
 	) -> Result<(), error::Error>;
+	/// session was ended by the client via `user/logout`
+	async fn on_logout(&self, _session: &Session) {}
+	/// valid session was invalidated by a newer login or got revoked
+	async fn on_session_invalidated(&self, _session: &Session) {}
+	/// login with unknown user id or wrong secret
+	async fn on_login_failed(
+		&self,
+		_user_id: &str,
+		_ip: Option<&str>,
+	) {
+	}
+	/// login/register was refused because the client is outdated
+	async fn on_version_rejected(&self, _client_version: u32) {}
 }
 
 pub struct UserLoginResource {
 	sessions: Arc<dyn sessions::SessionDB>,
 	users: Arc<dyn UserDB>,
 	events: Option<Arc<dyn UserLoginEvents>>,
-	ipdb: Option<IpDB>,
+	ipdb: Option<Arc<dyn IpLookup>>,
+	admin_key: Option<String>,
+	version: VersionConfig,
+	session_header: String,
+	session_cookie: Option<SessionCookie>,
+	deletion_grace_period: Duration,
 }
 
 impl UserLoginResource {
@@ -69,6 +129,11 @@ impl UserLoginResource {
 			users,
 			events: None,
 			ipdb: None,
+			admin_key: None,
+			version: VersionConfig::default(),
+			session_header: HEADER_SESSION.to_string(),
+			session_cookie: None,
+			deletion_grace_period: DELETION_GRACE_PERIOD,
 		}
 	}
 
@@ -77,23 +142,364 @@ impl UserLoginResource {
 		self.events = Some(events);
 	}
 
+	/// sets the lookup used to resolve the users country on login/register,
+	/// e.g. [`ipdb::IpDB`] or `ipdb::MaxMindIpDB` (`maxminddb` feature)
+	pub fn set_ip_db(&mut self, ipdb: impl IpLookup + 'static) {
+		self.ipdb = Some(Arc::new(ipdb));
+	}
+
+	/// key expected in the `X-GR-Admin-Key` header by the
+	/// [`admin::UserLoginAdmin`] endpoints, without it they reject every request
+	pub fn set_admin_key(&mut self, key: String) {
+		self.admin_key = Some(key);
+	}
+
+	/// minimum/latest client version and store urls sent to outdated clients
+	pub fn set_version_config(&mut self, version: VersionConfig) {
+		self.version = version;
+	}
+
+	/// header the session is sent/expected in, defaults to [`HEADER_SESSION`]
+	pub fn set_session_header(&mut self, header: impl Into<String>) {
+		self.session_header = header.into();
+	}
+
+	/// additionally set the session as `HttpOnly` cookie on login/register
+	/// and accept it in place of the session header from
+	/// [`SessionCookie::allowed_origins`]
+	pub fn set_session_cookie(&mut self, cookie: SessionCookie) {
+		self.session_cookie = Some(cookie);
+	}
+
+	/// time soft deleted users can be restored before
+	/// `purge_deleted_users` removes them, defaults to [`DELETION_GRACE_PERIOD`]
+	pub const fn set_deletion_grace_period(
+		&mut self,
+		grace_period: Duration,
+	) {
+		self.deletion_grace_period = grace_period;
+	}
+
+	#[must_use]
+	pub fn session_header(&self) -> &str {
+		&self.session_header
+	}
+
+	/// session from the session header or, in cookie mode, the session cookie
+	#[must_use]
+	pub fn session_from_headers(
+		&self,
+		headers: &HeaderMap,
+	) -> Option<String> {
+		headers
+			.get(self.session_header.as_str())
+			.and_then(|value| value.to_str().ok())
+			.map(String::from)
+			.or_else(|| {
+				self.session_cookie
+					.as_ref()
+					.and_then(|cookie| cookie.get(headers))
+			})
+	}
+
+	/// adds the session header (and cookie) to a login/register reply
+	fn session_reply(
+		&self,
+		reply: impl Reply,
+		session_id: &str,
+	) -> warp::reply::Response {
+		let mut response = warp::reply::with_header(
+			warp::reply::with_header(
+				reply,
+				"Access-Control-Expose-Headers",
+				self.session_header.as_str(),
+			),
+			self.session_header.as_str(),
+			session_id,
+		)
+		.into_response();
+
+		if let Some(cookie) = self
+			.session_cookie
+			.as_ref()
+			.filter(|_| !session_id.is_empty())
+		{
+			self.append_cookie(
+				&mut response,
+				&cookie.set(session_id),
+			);
+		}
+
+		response
+	}
+
+	/// removes the session cookie on logout
+	fn logout_reply(&self) -> warp::reply::Response {
+		let mut response = warp::reply().into_response();
+
+		if let Some(cookie) = self.session_cookie.as_ref() {
+			self.append_cookie(&mut response, &cookie.clear());
+		}
+
+		response
+	}
+
+	fn append_cookie(
+		&self,
+		response: &mut warp::reply::Response,
+		cookie: &str,
+	) {
+		match cookie.parse() {
+			Ok(value) => {
+				response.headers_mut().append(SET_COOKIE, value);
+			}
+			Err(e) => tracing::error!(
+				"invalid session cookie `{}`: {e}",
+				self.session_cookie
+					.as_ref()
+					.map_or("", |cookie| cookie.name.as_str())
+			),
+		}
+	}
+
+	/// invalidates all sessions of a user ("log out everywhere"),
+	/// returns the number of invalidated sessions
+	///
+	/// # Errors
+	///
+	/// fails with session db errors
+	pub async fn revoke_sessions(
+		&self,
+		user_id: &str,
+	) -> error::Result<usize> {
+		let revoked = if self.events.is_some() {
+			self.sessions.list_for_user(user_id).await?
+		} else {
+			Vec::new()
+		};
+
+		let count =
+			self.sessions.invalidate_all_for_user(user_id).await?;
+
+		for (_, session) in revoked.iter().filter(|(_, s)| s.valid) {
+			self.session_invalidated(session).await;
+		}
+
+		tracing::info!("revoked {} sessions of: {}", count, user_id);
+
+		Ok(count)
+	}
+
+	/// soft deletes the user and revokes all its sessions,
+	/// it can be restored via `restore_user` until the grace period is over
+	///
+	/// sessions are revoked first so a failed revoke leaves the user untouched
+	/// instead of deleted with sessions still passing `session_filter`,
+	/// the current session is revoked by key too as `SessionDB::list_for_user`
+	/// may not list a just created one yet (e.g. dynamo secondary index)
+	///
+	/// # Errors
+	///
+	/// fails if the user is unknown or with user/session db errors
+	pub async fn delete_user(
+		&self,
+		user_id: &str,
+	) -> error::Result<()> {
+		let mut user = self.get_user(user_id).await?;
+
+		self.revoke_sessions(user_id).await?;
+
+		if let Some(session) = user.session.as_deref() {
+			self.invalidate_previous_session(session).await;
+		}
+
+		if !user.is_deleted() {
+			user.deleted_at = Some(chrono::Utc::now().timestamp());
+			user.version += 1;
+			self.users.save_user(&user).await?;
+		}
+
+		tracing::info!("user deleted: {}", user_id);
+
+		Ok(())
+	}
+
+	/// undoes a soft delete, returns `false` if the user was not deleted
+	///
+	/// # Errors
+	///
+	/// fails if the user is unknown (e.g. already purged) or with user db errors
+	pub async fn restore_user(
+		&self,
+		user_id: &str,
+	) -> error::Result<bool> {
+		let mut user = self.get_user(user_id).await?;
+
+		if !user.is_deleted() {
+			return Ok(false);
+		}
+
+		user.deleted_at = None;
+		user.version += 1;
+		self.users.save_user(&user).await?;
+
+		tracing::info!("user restored: {}", user_id);
+
+		Ok(true)
+	}
+
+	/// permanently removes users soft deleted longer than the grace period ago,
+	/// returns the number of purged users
+	///
+	/// # Errors
+	///
+	/// fails with user db errors
+	pub async fn purge_deleted_users(&self) -> error::Result<usize> {
+		let grace_period =
+			chrono::Duration::from_std(self.deletion_grace_period)
+				.map_err(|e| error::Error::Custom(e.to_string()))?;
+		let before = (chrono::Utc::now() - grace_period).timestamp();
+
+		let mut count = 0;
+		for id in self.users.list_deleted_before(before).await? {
+			// users restored (and maybe deleted again) since the listing
+			// are kept by `delete_user`
+			if self.users.delete_user(&id, before).await? {
+				count += 1;
+			}
+		}
+
+		tracing::info!("purged {} deleted users", count);
+
+		Ok(count)
+	}
+
+	/// spawns a task calling `purge_deleted_users` every `interval`,
+	/// failed purges are logged and retried on the next tick
+	#[must_use]
+	pub fn spawn_purge(
+		self: &Arc<Self>,
+		interval: Duration,
+	) -> JoinHandle<()> {
+		let resource = self.clone();
+
+		tokio::spawn(async move {
+			let mut interval = tokio::time::interval(interval);
+
+			loop {
+				interval.tick().await;
+
+				if let Err(e) = resource.purge_deleted_users().await {
+					tracing::error!("purge deleted users error: {e}");
+				}
+			}
+		})
+	}
+
+	async fn get_user(&self, user_id: &str) -> error::Result<User> {
+		self.users.get_user(user_id).await.ok_or_else(|| {
+			error::Error::Custom(format!("user not found: {user_id}"))
+		})
+	}
+
+	/// invalidates all sessions of a user that were created on `device_id`,
+	/// returns the number of invalidated sessions
+	///
+	/// # Errors
 	///
-	pub fn set_ip_db(&mut self, ipdb: IpDB) {
-		self.ipdb = Some(ipdb);
+	/// fails with session db errors
+	pub async fn revoke_device(
+		&self,
+		user_id: &str,
+		device_id: &str,
+	) -> error::Result<usize> {
+		let mut count = 0;
+		for (key, session) in
+			self.sessions.list_for_user(user_id).await?
+		{
+			if session.valid
+				&& session.device_id.as_deref() == Some(device_id)
+				&& self.sessions.invalidate(&key).await.is_some()
+			{
+				self.session_invalidated(&session).await;
+				count += 1;
+			}
+		}
+
+		tracing::info!(
+			"revoked {} sessions of: {} on device: {}",
+			count,
+			user_id,
+			device_id
+		);
+
+		Ok(count)
 	}
 
 	pub async fn validate_session(
 		&self,
 		session: &str,
 	) -> SessionValidationResult {
-		match self.sessions.get(session).await {
-			Some(session) if session.valid => {
-				SessionValidationResult::Ok {
-					user_id: session.user_id,
-				}
+		match self.get_valid_session(session).await {
+			Ok(session) => SessionValidationResult::Ok {
+				user_id: session.user_id,
+			},
+			Err(SessionFailure::Invalid) => {
+				SessionValidationResult::Invalid
 			}
-			Some(_) => SessionValidationResult::Invalid,
-			None => SessionValidationResult::Unknown,
+			Err(SessionFailure::SessionNotFound) => {
+				SessionValidationResult::Unknown
+			}
+		}
+	}
+
+	/// invalidates the session, ending it like a logout from the client would
+	///
+	/// # Errors
+	///
+	/// fails if the session is invalid or unknown
+	pub async fn logout(
+		&self,
+		key: &str,
+	) -> Result<(), SessionFailure> {
+		let session = self.get_valid_session(key).await?;
+
+		self.sessions.invalidate(key).await;
+
+		if let Some(events) = self.events.as_ref() {
+			events.on_logout(&session).await;
+		}
+
+		tracing::info!("user logged out: {}", session.user_id);
+
+		Ok(())
+	}
+
+	async fn session_invalidated(&self, session: &Session) {
+		if let Some(events) = self.events.as_ref() {
+			events.on_session_invalidated(session).await;
+		}
+	}
+
+	async fn version_rejected(&self, client_version: u32) {
+		if let Some(events) = self.events.as_ref() {
+			events.on_version_rejected(client_version).await;
+		}
+	}
+
+	/// same as `validate_session` but returns the whole session including device info
+	///
+	/// # Errors
+	///
+	/// fails if the session is invalid or unknown
+	pub async fn get_valid_session(
+		&self,
+		session: &str,
+	) -> Result<Session, SessionFailure> {
+		match self.sessions.get(session).await {
+			Some(session) if session.valid => Ok(session),
+			Some(_) => Err(SessionFailure::Invalid),
+			None => Err(SessionFailure::SessionNotFound),
 		}
 	}
 
@@ -114,10 +520,18 @@ impl UserLoginResource {
 		login_request: schema::LoginRequest,
 		ip: Option<String>,
 	) -> error::Result<(schema::LoginResponse, String)> {
-		if !is_valid_version(login_request.clientVersion) {
+		let update = self.version.update_info(
+			login_request.clientVersion,
+			&login_request.platform,
+		);
+
+		if !self.version.is_supported(login_request.clientVersion) {
+			self.version_rejected(login_request.clientVersion).await;
+
 			return Ok((
 				schema::LoginResponse {
 					isOutdated: true,
+					update: update.into(),
 					..schema::LoginResponse::default()
 				},
 				String::new(),
@@ -130,29 +544,47 @@ impl UserLoginResource {
 			if user.secret == user_creds.secret
 				&& user.id == user_creds.id
 			{
-				if let Some(session) = user.session {
-					self.sessions.invalidate(&session).await;
+				if user.is_deleted() {
+					return Err(error::Error::Custom(format!(
+						"login of deleted user: {}",
+						user.id
+					)));
 				}
 
-				let session_id = self
-					.sessions
-					.create(Session::new(&user.id))
-					.await?;
-				user.session = Some(session_id.clone());
-
 				//TODO: add last_login to User, update last login here
 
-				user.country = self.country_from_ip(ip).await;
+				user.country = self.country_from_ip(ip.clone()).await;
 				user.language =
 					string_to_option(login_request.clientLanguage);
 
+				let session = Session::new(&user.id)
+					.with_device(
+						string_to_option(login_request.deviceId),
+						string_to_option(login_request.platform),
+					)
+					.with_locale(
+						user.country.clone(),
+						user.language.clone(),
+					);
+				let session_id =
+					self.sessions.create(session.clone()).await?;
+				let previous_session =
+					user.session.replace(session_id.clone());
+
 				user.version += 1;
-				if self.users.save_user(&user).await.is_err() {
-					tracing::error!("user save error");
+				// on a version conflict the user would keep referencing
+				// the previous session, which the next login could not end
+				if let Err(e) = self.users.save_user(&user).await {
+					self.sessions.invalidate(&session_id).await;
+					return Err(e);
+				}
+
+				if let Some(previous) = previous_session {
+					self.invalidate_previous_session(&previous).await;
 				}
 
 				if let Some(events) = self.events.as_ref() {
-					events.on_login(&user).await?;
+					events.on_login(&user, &session).await?;
 				}
 
 				tracing::info!("user succesfully logged in");
@@ -160,6 +592,7 @@ impl UserLoginResource {
 				return Ok((
 					schema::LoginResponse {
 						isOutdated: false,
+						update: update.into(),
 						..schema::LoginResponse::default()
 					},
 					session_id,
@@ -167,23 +600,53 @@ impl UserLoginResource {
 			}
 		}
 
+		if let Some(events) = self.events.as_ref() {
+			events
+				.on_login_failed(&user_creds.id, ip.as_deref())
+				.await;
+		}
+
 		Err(error::Error::Io(std::io::Error::new(
 			std::io::ErrorKind::NotFound,
 			"failed to retrieve user",
 		)))
 	}
 
+	async fn invalidate_previous_session(&self, key: &str) {
+		if self.events.is_none() {
+			self.sessions.invalidate(key).await;
+			return;
+		}
+
+		// `get` would extend the session right before ending it
+		let previous = self.sessions.peek(key).await;
+		if self.sessions.invalidate(key).await.is_some() {
+			if let Some(session) = previous.filter(|s| s.valid) {
+				self.session_invalidated(&session).await;
+			}
+		}
+	}
+
 	#[instrument(skip(self))]
 	async fn user_register(
 		&self,
-		client_version: u32,
-		client_language: String,
+		register_request: schema::RegisterRequest,
 		ip: Option<String>,
 	) -> error::Result<(schema::RegisterResponse, String)> {
-		if !is_valid_version(client_version) {
+		let update = self.version.update_info(
+			register_request.clientVersion,
+			&register_request.platform,
+		);
+
+		if !self.version.is_supported(register_request.clientVersion)
+		{
+			self.version_rejected(register_request.clientVersion)
+				.await;
+
 			return Ok((
 				schema::RegisterResponse {
 					isOutdated: true,
+					update: update.into(),
 					..schema::RegisterResponse::default()
 				},
 				String::new(),
@@ -191,12 +654,22 @@ impl UserLoginResource {
 		}
 
 		let country = self.country_from_ip(ip).await;
-		let client_language = string_to_option(client_language);
+		let client_language =
+			string_to_option(register_request.clientLanguage);
 
 		let mut new_user = User::new(country, client_language);
+		let new_session = Session::new(&new_user.id)
+			.with_device(
+				string_to_option(register_request.deviceId),
+				string_to_option(register_request.platform),
+			)
+			.with_locale(
+				new_user.country.clone(),
+				new_user.language.clone(),
+			);
 		let session = self
 			.sessions
-			.create(Session::new(&new_user.id))
+			.create(new_session.clone())
 			.await
 			.unwrap_or_default();
 		new_user.session = Some(session.clone());
@@ -204,7 +677,7 @@ impl UserLoginResource {
 		self.users.save_user(&new_user).await?;
 
 		if let Some(events) = self.events.as_ref() {
-			events.on_register(&new_user).await?;
+			events.on_register(&new_user, &new_session).await?;
 		}
 
 		tracing::info!("registered user: {}", &new_user.id);
@@ -218,6 +691,7 @@ impl UserLoginResource {
 				})
 				.into(),
 				isOutdated: false,
+				update: update.into(),
 				..schema::RegisterResponse::default()
 			},
 			session,
@@ -262,13 +736,24 @@ impl CustomModule for UserLogin {
 		let validate_session_filter =
 			warp::path!("user" / "validate_session")
 				.and(warp::post())
-				.and(userlogin)
-				.and(warp::header::header::<String>(HEADER_SESSION))
+				.and(with_session_id(userlogin.clone()))
 				.and_then(validate_session_fn);
 
+		let logout_filter = warp::path!("user" / "logout")
+			.and(warp::post())
+			.and(with_session_id(userlogin.clone()))
+			.and_then(logout_fn);
+
+		let delete_filter = warp::path!("user" / "delete")
+			.and(warp::post())
+			.and(with_session_id(userlogin))
+			.and_then(delete_fn);
+
 		let filters: BoxedFilter<(Box<dyn Reply>,)> = login_filter
 			.or(register_filter)
 			.or(validate_session_filter)
+			.or(logout_filter)
+			.or(delete_filter)
 			.map(move |reply| -> Box<dyn Reply> { Box::new(reply) })
 			.boxed();
 
@@ -292,16 +777,9 @@ async fn login_filter_fn(
 			let reply =
 				pbwarp::protobuf_reply(&response, content_type);
 
-			return Ok(warp::reply::with_header(
-				warp::reply::with_header(
-					reply,
-					"Access-Control-Expose-Headers",
-					HEADER_SESSION,
-				),
-				HEADER_SESSION,
-				session_id,
-			)
-			.into_response());
+			return Ok(
+				user_login_resource.session_reply(reply, &session_id)
+			);
 		}
 		Err(err) => tracing::error!("{}", err),
 	}
@@ -324,27 +802,16 @@ async fn register_filter_fn(
 		.or_else(|| addr.map(|addr| addr.ip().to_string()));
 
 	match user_login_resource
-		.user_register(
-			register_request.clientVersion,
-			register_request.clientLanguage,
-			ip,
-		)
+		.user_register(register_request, ip)
 		.await
 	{
 		Ok((response, session_id)) => {
 			let reply =
 				pbwarp::protobuf_reply(&response, content_type);
 
-			return Ok(warp::reply::with_header(
-				warp::reply::with_header(
-					reply,
-					"Access-Control-Expose-Headers",
-					HEADER_SESSION,
-				),
-				HEADER_SESSION,
-				session_id,
-			)
-			.into_response());
+			return Ok(
+				user_login_resource.session_reply(reply, &session_id)
+			);
 		}
 		Err(err) => tracing::error!("{}", err),
 	}
@@ -365,19 +832,110 @@ async fn validate_session_fn(
 		.map(|_| warp::reply())
 }
 
+async fn logout_fn(
+	resource: Arc<UserLoginResource>,
+	session: String,
+) -> Result<impl warp::Reply, Rejection> {
+	resource
+		.logout(&session)
+		.await
+		.map(|()| resource.logout_reply())
+		.map_err(warp::reject::custom)
+}
+
+async fn delete_fn(
+	resource: Arc<UserLoginResource>,
+	session: String,
+) -> Result<impl warp::Reply, Rejection> {
+	let user_id = handle_session(resource.clone(), session).await?;
+
+	match resource.delete_user(&user_id).await {
+		Ok(()) => Ok(resource.logout_reply()),
+		Err(err) => {
+			tracing::error!("{}", err);
+
+			Ok(warp::reply::with_status(
+				String::from("failed to delete user"),
+				warp::hyper::StatusCode::BAD_REQUEST,
+			)
+			.into_response())
+		}
+	}
+}
+
 /// Returns filter that checks session status, which returns rejection if session is not Ok.
 /// If session is Ok, request passes through normally
 ///
+/// Sessions of soft deleted users are revoked and therefore rejected as invalid.
+///
 /// Intended to be used for composing warp filters
 pub fn session_filter(
 	resource: Arc<UserLoginResource>,
 ) -> impl Filter<Extract = (String,), Error = Rejection> + Clone {
-	warp::any()
-		.map(move || resource.clone())
-		.and(warp::header::header::<String>(HEADER_SESSION))
+	with_session_id(warp::any().map(move || resource.clone()))
 		.and_then(handle_session)
 }
 
+/// Same as [`session_filter`] but extracts the whole [`Session`] including device info
+///
+/// Intended to be used for composing warp filters
+pub fn session_info_filter(
+	resource: Arc<UserLoginResource>,
+) -> impl Filter<Extract = (Session,), Error = Rejection> + Clone {
+	with_session_id(warp::any().map(move || resource.clone()))
+		.and_then(handle_session_info)
+}
+
+/// Same as [`session_filter`] but extracts a [`SessionContext`] with the users
+/// country/language cached on the session, saving handlers a `UserDB` lookup
+///
+/// Intended to be used for composing warp filters
+pub fn session_user_filter(
+	resource: Arc<UserLoginResource>,
+) -> impl Filter<Extract = (SessionContext,), Error = Rejection> + Clone
+{
+	session_info_filter(resource).map(SessionContext::from)
+}
+
+/// extends a resource filter by the session id sent with the request,
+/// rejects with [`SessionFailure::SessionNotFound`] if there is none
+fn with_session_id(
+	resource: impl Filter<
+			Extract = (Arc<UserLoginResource>,),
+			Error = Infallible,
+		> + Clone,
+) -> impl Filter<
+	Extract = (Arc<UserLoginResource>, String),
+	Error = Rejection,
+> + Clone {
+	resource
+		.and(warp::header::headers_cloned())
+		.and_then(extract_session_id)
+		.untuple_one()
+}
+
+async fn extract_session_id(
+	resource: Arc<UserLoginResource>,
+	headers: HeaderMap,
+) -> Result<(Arc<UserLoginResource>, String), Rejection> {
+	let session =
+		resource.session_from_headers(&headers).ok_or_else(|| {
+			warp::reject::custom(SessionFailure::SessionNotFound)
+		})?;
+
+	Ok((resource, session))
+}
+
+async fn handle_session_info(
+	resource: Arc<UserLoginResource>,
+	session: String,
+) -> Result<Session, Rejection> {
+	resource
+		.get_valid_session(&session)
+		.await
+		.map_err(warp::reject::custom)
+}
+
 async fn handle_session(
 	resource: Arc<UserLoginResource>,
 	session: String,
@@ -393,6 +951,9 @@ async fn handle_session(
 	}
 }
 
+/// only checks against the default [`MIN_CLIENT_VERSION`],
+/// ignoring `UserLoginResource::set_version_config`
+#[deprecated(note = "use `VersionConfig::is_supported` instead")]
 #[must_use]
 pub const fn is_valid_version(client_version: u32) -> bool {
 	client_version >= MIN_CLIENT_VERSION
@@ -412,16 +973,20 @@ mod tests {
 		rejection::{self, handle_rejection},
 		schema::{self, RegisterResponse},
 		userlogin::{
-			session_filter,
+			cookie::SessionCookie,
+			ipdb::IpLookup,
+			session_filter, session_info_filter, session_user_filter,
 			sessions::{InMemorySessionDB, Session, SessionDB},
 			user::{in_memory::InMemoryUserDB, User, UserDB},
-			UserLogin, UserLoginResource, HEADER_SESSION,
+			version::VersionConfig,
+			SessionContext, UserLogin, UserLoginEvents,
+			UserLoginResource, HEADER_SESSION,
 		},
 		CustomModule, CustomServer, Module, ModuleResources,
 	};
 	use frunk::{hlist, Hlist};
 	use protobuf::Message;
-	use std::{collections::HashMap, sync::Arc};
+	use std::{collections::HashMap, sync::Arc, time::Duration};
 	use tokio::sync::Mutex;
 	use uuid::Uuid;
 	use warp::{hyper::StatusCode, Filter};
@@ -573,8 +1138,8 @@ mod tests {
 		let sessions = sessions_with_session(
 			"sid1",
 			Session {
-				user_id: "uid".to_string(),
 				valid: false,
+				..Session::new("uid")
 			},
 		);
 
@@ -641,6 +1206,58 @@ mod tests {
 		assert_eq!(reply.body(), "uid");
 	}
 
+	#[tokio::test]
+	async fn test_session_user_filter() {
+		let users = Arc::new(InMemoryUserDB::default());
+		let mut resource = UserLoginResource::new(
+			Arc::new(InMemorySessionDB::default()),
+			users.clone(),
+		);
+		resource.set_ip_db(StaticLookup);
+		let resource = Arc::new(resource);
+
+		let (response, session) = resource
+			.user_register(
+				schema::RegisterRequest {
+					clientVersion: 1000000,
+					clientLanguage: "de".to_string(),
+					platform: "android".to_string(),
+					..schema::RegisterRequest::default()
+				},
+				Some("1.2.3.4".into()),
+			)
+			.await
+			.unwrap();
+
+		// context is served from the session, not the user db
+		users.db.lock().await.clear();
+
+		let filter = warp::path!("test")
+			.and(session_user_filter(resource))
+			.map(|context: SessionContext| {
+				assert_eq!(
+					context,
+					SessionContext {
+						user_id: context.user_id.clone(),
+						country: Some("DE".to_string()),
+						language: Some("de".to_string()),
+						platform: Some("android".to_string()),
+					}
+				);
+				context.user_id
+			})
+			.recover(handle_rejection);
+
+		let reply = warp::test::request()
+			.header(HEADER_SESSION, &session)
+			.path("/test")
+			.reply(&filter)
+			.await;
+
+		assert_eq!(reply.status(), 200);
+		assert_eq!(reply.body(), &response.user.unwrap().id);
+	}
+
 	#[tokio::test]
 	async fn test_reg_response() {
 		let sessions = Arc::new(InMemorySessionDB::default());
@@ -733,7 +1350,14 @@ mod tests {
 			server.get_server_resources().pluck();
 
 		let (response, session) = user_login_resource
-			.user_register(1000000, "en-CA".to_string(), None)
+			.user_register(
+				schema::RegisterRequest {
+					clientVersion: 1000000,
+					clientLanguage: "en-CA".to_string(),
+					..schema::RegisterRequest::default()
+				},
+				None,
+			)
 			.await
 			.unwrap();
 
@@ -807,6 +1431,686 @@ mod tests {
 		assert_eq!(db_user.session, Some(session.clone()));
 	}
 
+	struct StaticLookup;
+
+	#[async_trait::async_trait]
+	impl IpLookup for StaticLookup {
+		async fn lookup(&self, ip: &str) -> Option<String> {
+			(ip == "1.2.3.4").then(|| String::from("DE"))
+		}
+	}
+
+	#[tokio::test]
+	async fn test_register_country_lookup() {
+		let users = Arc::new(InMemoryUserDB::default());
+		let mut resource = UserLoginResource::new(
+			Arc::new(InMemorySessionDB::default()),
+			users.clone(),
+		);
+		resource.set_ip_db(StaticLookup);
+
+		let (response, _) = resource
+			.user_register(
+				schema::RegisterRequest {
+					clientVersion: 1000000,
+					..schema::RegisterRequest::default()
+				},
+				Some("1.2.3.4".into()),
+			)
+			.await
+			.unwrap();
+
+		let user =
+			users.get_user(&response.user.unwrap().id).await.unwrap();
+		assert_eq!(user.country, Some("DE".to_string()));
+	}
+
+	#[tokio::test]
+	async fn test_register_update_info() {
+		let mut resource = UserLoginResource::new(
+			Arc::new(InMemorySessionDB::default()),
+			Arc::new(InMemoryUserDB::default()),
+		);
+		resource.set_version_config(VersionConfig {
+			min_version: 10,
+			latest_version: Some(20),
+			store_urls: HashMap::from([(
+				"android".to_string(),
+				"https://play.google.com/store".to_string(),
+			)]),
+		});
+
+		let register = |client_version| schema::RegisterRequest {
+			clientVersion: client_version,
+			platform: "android".to_string(),
+			..schema::RegisterRequest::default()
+		};
+
+		let (response, session) =
+			resource.user_register(register(5), None).await.unwrap();
+		assert!(response.isOutdated);
+		assert!(response.update.unwrap().forceUpdate);
+		assert!(session.is_empty());
+
+		let (response, session) =
+			resource.user_register(register(15), None).await.unwrap();
+		assert!(!response.isOutdated);
+		assert!(!response.update.get_ref().forceUpdate);
+		assert_eq!(
+			response.update.get_ref().storeUrl,
+			"https://play.google.com/store"
+		);
+		assert!(!session.is_empty());
+
+		let (response, _) =
+			resource.user_register(register(20), None).await.unwrap();
+		assert!(response.update.is_none());
+	}
+
+	#[tokio::test]
+	async fn test_login_device() {
+		let sessions = Arc::new(InMemorySessionDB::default());
+		let users = Arc::new(InMemoryUserDB::default());
+		let resource = Arc::new(UserLoginResource::new(
+			sessions.clone(),
+			users.clone(),
+		));
+
+		let (response, old_phone) = resource
+			.user_register(
+				schema::RegisterRequest {
+					clientVersion: 1000000,
+					deviceId: "old-phone".to_string(),
+					platform: "ios".to_string(),
+					..schema::RegisterRequest::default()
+				},
+				None,
+			)
+			.await
+			.unwrap();
+		let user = response.user.unwrap();
+
+		// a second device of the same user, e.g. created via a different login path
+		let tablet = sessions
+			.create(Session::new(&user.id).with_device(
+				Some("tablet".to_string()),
+				Some("android".to_string()),
+			))
+			.await
+			.unwrap();
+
+		let filter = warp::path!("test")
+			.and(session_info_filter(resource.clone()))
+			.map(|session: Session| {
+				format!(
+					"{}/{}",
+					session.device_id.unwrap(),
+					session.platform.unwrap()
+				)
+			})
+			.recover(handle_rejection);
+
+		let reply = warp::test::request()
+			.header(HEADER_SESSION, &old_phone)
+			.path("/test")
+			.reply(&filter)
+			.await;
+
+		assert_eq!(reply.status(), 200);
+		assert_eq!(reply.body(), "old-phone/ios");
+
+		assert_eq!(
+			resource
+				.revoke_device(&user.id, "old-phone")
+				.await
+				.unwrap(),
+			1
+		);
+		assert!(!sessions.get(&old_phone).await.unwrap().valid);
+		assert!(sessions.get(&tablet).await.unwrap().valid);
+	}
+
+	#[derive(Default)]
+	struct RecordingEvents {
+		events: std::sync::Mutex<Vec<String>>,
+	}
+
+	impl RecordingEvents {
+		fn push(&self, event: String) {
+			self.events.lock().unwrap().push(event);
+		}
+
+		fn take(&self) -> Vec<String> {
+			std::mem::take(&mut *self.events.lock().unwrap())
+		}
+	}
+
+	#[async_trait::async_trait]
+	impl UserLoginEvents for RecordingEvents {
+		async fn on_login(
+			&self,
+			user: &User,
+			_: &Session,
+		) -> Result<(), crate::error::Error> {
+			self.push(format!("login:{}", user.id));
+			Ok(())
+		}
+		async fn on_register(
+			&self,
+			user: &User,
+			_: &Session,
+		) -> Result<(), crate::error::Error> {
+			self.push(format!("register:{}", user.id));
+			Ok(())
+		}
+		async fn on_logout(&self, session: &Session) {
+			self.push(format!("logout:{}", session.user_id));
+		}
+		async fn on_session_invalidated(&self, session: &Session) {
+			self.push(format!("invalidated:{}", session.user_id));
+		}
+		async fn on_login_failed(
+			&self,
+			user_id: &str,
+			ip: Option<&str>,
+		) {
+			self.push(format!("failed:{}:{}", user_id, ip.unwrap()));
+		}
+		async fn on_version_rejected(&self, client_version: u32) {
+			self.push(format!("version:{}", client_version));
+		}
+	}
+
+	#[tokio::test]
+	async fn test_events() {
+		let sessions = Arc::new(InMemorySessionDB::default());
+		let users = Arc::new(InMemoryUserDB::default());
+		let events = Arc::new(RecordingEvents::default());
+
+		let mut resource =
+			UserLoginResource::new(sessions.clone(), users.clone());
+		resource.set_events(events.clone());
+
+		let (response, session) = resource
+			.user_register(
+				schema::RegisterRequest {
+					clientVersion: 1000000,
+					..schema::RegisterRequest::default()
+				},
+				None,
+			)
+			.await
+			.unwrap();
+		let creds = response.user.unwrap();
+		let id = creds.id.clone();
+		assert_eq!(events.take(), vec![format!("register:{}", id)]);
+
+		let login = |secret: String| schema::LoginRequest {
+			user: Some(schema::UserCredentials {
+				id: id.clone(),
+				secret,
+				..schema::UserCredentials::default()
+			})
+			.into(),
+			clientVersion: 1000000,
+			..schema::LoginRequest::default()
+		};
+
+		let (_, new_session) = resource
+			.user_login(login(creds.secret.clone()), None)
+			.await
+			.unwrap();
+		assert_eq!(
+			events.take(),
+			vec![
+				format!("invalidated:{}", id),
+				format!("login:{}", id)
+			]
+		);
+		assert!(!sessions.get(&session).await.unwrap().valid);
+
+		assert!(resource
+			.user_login(
+				login("wrong".to_string()),
+				Some("1.2.3.4".into())
+			)
+			.await
+			.is_err());
+		assert_eq!(
+			events.take(),
+			vec![format!("failed:{}:1.2.3.4", id)]
+		);
+
+		let (response, _) = resource
+			.user_login(
+				schema::LoginRequest {
+					clientVersion: 0,
+					..login(creds.secret.clone())
+				},
+				None,
+			)
+			.await
+			.unwrap();
+		assert!(response.isOutdated);
+		assert_eq!(events.take(), vec!["version:0".to_string()]);
+
+		resource.logout(&new_session).await.unwrap();
+		assert_eq!(events.take(), vec![format!("logout:{}", id)]);
+		assert!(!sessions.get(&new_session).await.unwrap().valid);
+
+		// already logged out
+		assert!(resource.logout(&new_session).await.is_err());
+		assert!(events.take().is_empty());
+	}
+
+	#[tokio::test]
+	async fn test_logout_filter() {
+		let sessions = Arc::new(InMemorySessionDB::default());
+		let users = Arc::new(InMemoryUserDB::default());
+		let session =
+			sessions.create(Session::new("uid")).await.unwrap();
+
+		let server = Arc::new(InMemoryServer {
+			resources: hlist![Arc::new(UserLoginResource::new(
+				sessions.clone(),
+				users
+			))],
+		});
+
+		let filter = UserLogin::create_filter(server)
+			.recover(handle_rejection);
+
+		let reply = warp::test::request()
+			.method("POST")
+			.header(HEADER_SESSION, &session)
+			.path("/user/logout")
+			.reply(&filter)
+			.await;
+
+		assert_eq!(reply.status(), 200);
+		assert!(!sessions.get(&session).await.unwrap().valid);
+
+		let reply = warp::test::request()
+			.method("POST")
+			.header(HEADER_SESSION, &session)
+			.path("/user/logout")
+			.reply(&filter)
+			.await;
+
+		assert_eq!(reply.status(), StatusCode::ACCEPTED);
+	}
+
+	#[tokio::test]
+	async fn test_session_cookie() {
+		let sessions = Arc::new(InMemorySessionDB::default());
+		let users = Arc::new(InMemoryUserDB::default());
+
+		let mut resource =
+			UserLoginResource::new(sessions.clone(), users);
+		resource.set_session_header("X-Session");
+		resource.set_session_cookie(SessionCookie {
+			allowed_origins: vec!["https://game.example.com".into()],
+			..SessionCookie::default()
+		});
+
+		let server = Arc::new(InMemoryServer {
+			resources: hlist![Arc::new(resource)],
+		});
+
+		let filter = UserLogin::create_filter(server)
+			.recover(handle_rejection);
+
+		let request = schema::RegisterRequest {
+			clientVersion: 1000000,
+			..schema::RegisterRequest::default()
+		};
+		let reply = warp::test::request()
+			.method("POST")
+			.body(request.write_to_bytes().unwrap())
+			.path("/user/register")
+			.reply(&filter)
+			.await;
+
+		assert_eq!(reply.status(), 200);
+		assert!(reply.headers().get(HEADER_SESSION).is_none());
+		let session = reply.headers()["X-Session"]
+			.to_str()
+			.unwrap()
+			.to_string();
+		assert_eq!(
+			reply.headers()["Set-Cookie"],
+			SessionCookie::default().set(&session)
+		);
+
+		let reply = warp::test::request()
+			.method("POST")
+			.header("X-Session", &session)
+			.path("/user/validate_session")
+			.reply(&filter)
+			.await;
+
+		assert_eq!(reply.status(), 200);
+
+		// cross site request carrying the cookie
+		let reply = warp::test::request()
+			.method("POST")
+			.header("Cookie", format!("gr_session={session}"))
+			.header("Origin", "https://evil.example.com")
+			.path("/user/logout")
+			.reply(&filter)
+			.await;
+
+		assert_eq!(reply.status(), StatusCode::ACCEPTED);
+		assert!(sessions.get(&session).await.unwrap().valid);
+
+		let reply = warp::test::request()
+			.method("POST")
+			.header("Cookie", format!("gr_session={session}"))
+			.header("Origin", "https://game.example.com")
+			.path("/user/logout")
+			.reply(&filter)
+			.await;
+
+		assert_eq!(reply.status(), 200);
+		assert_eq!(
+			reply.headers()["Set-Cookie"],
+			SessionCookie::default().clear()
+		);
+		assert!(!sessions.get(&session).await.unwrap().valid);
+
+		let reply = warp::test::request()
+			.method("POST")
+			.header("Cookie", format!("gr_session={session}"))
+			.header("Origin", "https://game.example.com")
+			.path("/user/validate_session")
+			.reply(&filter)
+			.await;
+
+		assert_eq!(reply.status(), StatusCode::ACCEPTED);
+		let rejection =
+			schema::RejectionResponse::parse_from_bytes(reply.body())
+				.unwrap();
+		assert_eq!(
+			rejection.sessionFilterRejection,
+			schema::RejectionResponse_SessionFilterRejection::INVALID
+		);
+	}
+
+	#[tokio::test]
+	async fn test_delete_user() {
+		let sessions = Arc::new(InMemorySessionDB::default());
+		let users = Arc::new(InMemoryUserDB::default());
+		let resource = Arc::new(UserLoginResource::new(
+			sessions.clone(),
+			users.clone(),
+		));
+
+		let (response, session) = resource
+			.user_register(
+				schema::RegisterRequest {
+					clientVersion: 1000000,
+					..schema::RegisterRequest::default()
+				},
+				None,
+			)
+			.await
+			.unwrap();
+		let creds = response.user.unwrap();
+
+		let server = Arc::new(InMemoryServer {
+			resources: hlist![resource.clone()],
+		});
+		let filter = UserLogin::create_filter(server)
+			.recover(handle_rejection);
+
+		let reply = warp::test::request()
+			.method("POST")
+			.header(HEADER_SESSION, &session)
+			.path("/user/delete")
+			.reply(&filter)
+			.await;
+
+		assert_eq!(reply.status(), 200);
+		assert!(users
+			.get_user(&creds.id)
+			.await
+			.unwrap()
+			.is_deleted());
+		assert!(!sessions.get(&session).await.unwrap().valid);
+
+		let login = schema::LoginRequest {
+			user: Some(creds.clone()).into(),
+			clientVersion: 1000000,
+			..schema::LoginRequest::default()
+		};
+		assert!(resource
+			.user_login(login.clone(), None)
+			.await
+			.is_err());
+
+		// grace period not over yet
+		assert_eq!(resource.purge_deleted_users().await.unwrap(), 0);
+
+		assert!(resource.restore_user(&creds.id).await.unwrap());
+		assert!(resource.user_login(login, None).await.is_ok());
+	}
+
+	#[tokio::test]
+	async fn test_delete_user_revoke_failure() {
+		/// e.g. dynamo table without the `user_id-index`
+		struct UnlistableSessions;
+
+		#[async_trait::async_trait]
+		impl SessionDB for UnlistableSessions {
+			async fn create(
+				&self,
+				_session: Session,
+			) -> crate::error::Result<String> {
+				Ok(Session::new_key())
+			}
+			async fn invalidate(&self, _key: &str) -> Option<()> {
+				None
+			}
+			async fn get(&self, _key: &str) -> Option<Session> {
+				None
+			}
+			async fn list_for_user(
+				&self,
+				_user_id: &str,
+			) -> crate::error::Result<Vec<(String, Session)>> {
+				Err(crate::error::Error::Custom("no index".into()))
+			}
+		}
+
+		let users = Arc::new(InMemoryUserDB::default());
+		let resource = UserLoginResource::new(
+			Arc::new(UnlistableSessions),
+			users.clone(),
+		);
+
+		let user = User::new(None, None);
+		users.save_user(&user).await.unwrap();
+
+		assert!(resource.delete_user(&user.id).await.is_err());
+		assert!(!users
+			.get_user(&user.id)
+			.await
+			.unwrap()
+			.is_deleted());
+	}
+
+	#[tokio::test]
+	async fn test_delete_user_unlisted_session() {
+		/// index lagging behind like an eventually consistent dynamo gsi
+		struct LaggingSessions(InMemorySessionDB);
+
+		#[async_trait::async_trait]
+		impl SessionDB for LaggingSessions {
+			async fn create(
+				&self,
+				session: Session,
+			) -> crate::error::Result<String> {
+				self.0.create(session).await
+			}
+			async fn invalidate(&self, key: &str) -> Option<()> {
+				self.0.invalidate(key).await
+			}
+			async fn get(&self, key: &str) -> Option<Session> {
+				self.0.get(key).await
+			}
+			async fn list_for_user(
+				&self,
+				_user_id: &str,
+			) -> crate::error::Result<Vec<(String, Session)>> {
+				Ok(Vec::new())
+			}
+		}
+
+		let sessions =
+			Arc::new(LaggingSessions(InMemorySessionDB::default()));
+		let resource = UserLoginResource::new(
+			sessions.clone(),
+			Arc::new(InMemoryUserDB::default()),
+		);
+
+		let (response, session) = resource
+			.user_register(
+				schema::RegisterRequest {
+					clientVersion: 1000000,
+					..schema::RegisterRequest::default()
+				},
+				None,
+			)
+			.await
+			.unwrap();
+
+		resource
+			.delete_user(&response.user.unwrap().id)
+			.await
+			.unwrap();
+
+		assert!(!sessions.get(&session).await.unwrap().valid);
+	}
+
+	#[tokio::test]
+	async fn test_login_save_conflict() {
+		/// rejects every save like an optimistic lock conflict would
+		struct ConflictingUsers(InMemoryUserDB);
+
+		#[async_trait::async_trait]
+		impl UserDB for ConflictingUsers {
+			async fn get_user(&self, key: &str) -> Option<User> {
+				self.0.get_user(key).await
+			}
+			async fn save_user(
+				&self,
+				_u: &User,
+			) -> crate::error::Result<()> {
+				Err(crate::error::Error::Custom("conflict".into()))
+			}
+			async fn delete_user(
+				&self,
+				key: &str,
+				timestamp: i64,
+			) -> crate::error::Result<bool> {
+				self.0.delete_user(key, timestamp).await
+			}
+			async fn list_deleted_before(
+				&self,
+				timestamp: i64,
+			) -> crate::error::Result<Vec<String>> {
+				self.0.list_deleted_before(timestamp).await
+			}
+		}
+
+		let sessions = Arc::new(InMemorySessionDB::default());
+		let previous =
+			sessions.create(Session::new("uid")).await.unwrap();
+		let user = User {
+			id: "uid".into(),
+			secret: "secret".into(),
+			session: Some(previous.clone()),
+			..User::default()
+		};
+		let users = ConflictingUsers(InMemoryUserDB::default());
+		users.0.save_user(&user).await.unwrap();
+
+		let resource =
+			UserLoginResource::new(sessions.clone(), Arc::new(users));
+
+		let login = schema::LoginRequest {
+			user: Some(schema::UserCredentials {
+				id: user.id.clone(),
+				secret: user.secret.clone(),
+				..schema::UserCredentials::default()
+			})
+			.into(),
+			clientVersion: 1000000,
+			..schema::LoginRequest::default()
+		};
+		assert!(resource.user_login(login, None).await.is_err());
+
+		// the still referenced session stays valid, the new one is unusable
+		assert!(sessions.get(&previous).await.unwrap().valid);
+		let listed = sessions.list_for_user("uid").await.unwrap();
+		assert_eq!(listed.iter().filter(|(_, s)| s.valid).count(), 1);
+	}
+
+	#[tokio::test]
+	async fn test_purge_deleted_users() {
+		let users = Arc::new(InMemoryUserDB::default());
+		let mut resource = UserLoginResource::new(
+			Arc::new(InMemorySessionDB::default()),
+			users.clone(),
+		);
+		resource.set_deletion_grace_period(Duration::from_secs(60));
+
+		let now = chrono::Utc::now().timestamp();
+		let expired = User {
+			deleted_at: Some(now - 120),
+			..User::new(None, None)
+		};
+		let recent = User {
+			deleted_at: Some(now),
+			..User::new(None, None)
+		};
+		let active = User::new(None, None);
+		for user in [&expired, &recent, &active] {
+			users.save_user(user).await.unwrap();
+		}
+
+		assert_eq!(resource.purge_deleted_users().await.unwrap(), 1);
+		// purge only removes users that are still soft deleted
+		assert!(!users.delete_user(&active.id, now).await.unwrap());
+		// nor those deleted again after the cutoff
+		assert!(!users.delete_user(&recent.id, now).await.unwrap());
+
+		assert!(users.get_user(&expired.id).await.is_none());
+		assert!(users.get_user(&recent.id).await.is_some());
+		assert!(users.get_user(&active.id).await.is_some());
+	}
+
+	#[tokio::test]
+	async fn test_revoke_sessions() {
+		let sessions = Arc::new(InMemorySessionDB::default());
+		let users = Arc::new(InMemoryUserDB::default());
+
+		let s1 = sessions.create(Session::new("uid")).await.unwrap();
+		let s2 = sessions.create(Session::new("uid")).await.unwrap();
+		sessions.invalidate(&s2).await.unwrap();
+
+		let resource =
+			UserLoginResource::new(sessions.clone(), users);
+
+		assert_eq!(resource.revoke_sessions("uid").await.unwrap(), 1);
+		assert!(!sessions.get(&s1).await.unwrap().valid);
+		assert_eq!(
+			sessions.list_for_user("uid").await.unwrap().len(),
+			2
+		);
+		assert_eq!(resource.revoke_sessions("uid").await.unwrap(), 0);
+	}
+
 	#[tokio::test]
 	async fn test_empty_bytes() {
 		let sessions = Arc::new(InMemorySessionDB::default());
@@ -1,13 +1,15 @@
 use super::{Session, SessionDB};
 use crate::{
-	dynamo_util::{db_key, table_init, DynamoHashMap},
+	dynamo_util::{
+		db_key, index_name, table_init_with_indices, DynamoHashMap,
+	},
 	error::{Error, Result},
 };
 use async_trait::async_trait;
 use chrono::{DateTime, Duration, NaiveDateTime, Utc};
 use rusoto_dynamodb::{
-	AttributeValue, DynamoDb, DynamoDbClient, PutItemInput,
-	UpdateItemInput,
+	AttributeValue, DynamoDb, DynamoDbClient, GetItemInput,
+	PutItemInput, QueryInput, UpdateItemInput,
 };
 use std::{
 	collections::HashMap,
@@ -21,6 +23,10 @@ struct DynamoSession {
 	user_id: String,
 	valid: bool,
 	ttl: i64,
+	device_id: Option<String>,
+	platform: Option<String>,
+	country: Option<String>,
+	language: Option<String>,
 }
 
 impl DynamoSession {
@@ -30,6 +36,10 @@ impl DynamoSession {
 		Self {
 			user_id: session.user_id,
 			valid: session.valid,
+			device_id: session.device_id,
+			platform: session.platform,
+			country: session.country,
+			language: session.language,
 			id,
 			ttl,
 		}
@@ -41,6 +51,10 @@ impl From<DynamoSession> for Session {
 		Self {
 			user_id: session.user_id,
 			valid: session.valid,
+			device_id: session.device_id,
+			platform: session.platform,
+			country: session.country,
+			language: session.language,
 		}
 	}
 }
@@ -58,11 +72,15 @@ impl DynamoSessionDB {
 	///
 	/// local table init could fail creating table of the check
 	/// for the existance of the right table remote could fail
+	///
+	/// the table is expected to have a global secondary index on `user_id`
+	/// (see [`index_name`]) used by `list_for_user`
 	pub async fn new(
 		table_name: &str,
 		db: DynamoDbClient,
 	) -> Result<Self> {
-		table_init(&db, table_name).await?;
+		table_init_with_indices(&db, table_name, &["user_id"])
+			.await?;
 		Ok(Self {
 			db,
 			table: table_name.to_string(),
@@ -107,6 +125,42 @@ impl From<DynamoSession> for DynamoHashMap {
 				..AttributeValue::default()
 			},
 		);
+		if let Some(device_id) = session.device_id {
+			map.insert(
+				"device_id".to_string(),
+				AttributeValue {
+					s: Some(device_id),
+					..AttributeValue::default()
+				},
+			);
+		}
+		if let Some(platform) = session.platform {
+			map.insert(
+				"platform".to_string(),
+				AttributeValue {
+					s: Some(platform),
+					..AttributeValue::default()
+				},
+			);
+		}
+		if let Some(country) = session.country {
+			map.insert(
+				"country".to_string(),
+				AttributeValue {
+					s: Some(country),
+					..AttributeValue::default()
+				},
+			);
+		}
+		if let Some(language) = session.language {
+			map.insert(
+				"language".to_string(),
+				AttributeValue {
+					s: Some(language),
+					..AttributeValue::default()
+				},
+			);
+		}
 
 		map
 	}
@@ -135,6 +189,18 @@ impl TryFrom<DynamoHashMap> for DynamoSession {
 				.and_then(|attr| attr.n.as_ref())
 				.and_then(|attr| attr.parse::<i64>().ok())
 				.ok_or(Error::DynamoDeserialize("ttl"))?,
+			device_id: attributes
+				.get("device_id")
+				.and_then(|attr| attr.s.clone()),
+			platform: attributes
+				.get("platform")
+				.and_then(|attr| attr.s.clone()),
+			country: attributes
+				.get("country")
+				.and_then(|attr| attr.s.clone()),
+			language: attributes
+				.get("language")
+				.and_then(|attr| attr.s.clone()),
 		})
 	}
 }
@@ -249,6 +315,83 @@ impl SessionDB for DynamoSessionDB {
 
 		Some(item.into())
 	}
+
+	#[instrument(skip(self))]
+	async fn peek(&self, key: &str) -> Option<Session> {
+		tracing::trace!("SessionDB::peek");
+
+		let item: DynamoSession = self
+			.db
+			.get_item(GetItemInput {
+				table_name: self.table.clone(),
+				key: db_key("id", key),
+				..GetItemInput::default()
+			})
+			.await
+			.map_err(|e| tracing::error!("get error: {e}"))
+			.ok()?
+			.item?
+			.try_into()
+			.map_err(|e| tracing::error!("try_into error: {e}"))
+			.ok()?;
+
+		// expired items linger until dynamo removes them
+		(item.ttl >= Utc::now().timestamp()).then(|| item.into())
+	}
+
+	#[instrument(skip(self), err)]
+	async fn list_for_user(
+		&self,
+		user_id: &str,
+	) -> Result<Vec<(String, Session)>> {
+		tracing::trace!("SessionDB::list_for_user");
+
+		let mut value_map = HashMap::new();
+		value_map.insert(
+			":uid".to_string(),
+			AttributeValue {
+				s: Some(user_id.to_string()),
+				..AttributeValue::default()
+			},
+		);
+
+		let now = Utc::now().timestamp();
+		let mut sessions = Vec::new();
+		let mut exclusive_start_key = None;
+
+		loop {
+			let output = self
+				.db
+				.query(QueryInput {
+					table_name: self.table.clone(),
+					index_name: Some(index_name("user_id")),
+					key_condition_expression: Some(String::from(
+						"user_id = :uid",
+					)),
+					expression_attribute_values: Some(
+						value_map.clone(),
+					),
+					exclusive_start_key,
+					..QueryInput::default()
+				})
+				.await?;
+
+			for item in output.items.unwrap_or_default() {
+				let item: DynamoSession = item.try_into()?;
+				// expired items may still be returned until dynamo deletes them
+				if item.ttl >= now {
+					sessions.push((item.id.clone(), item.into()));
+				}
+			}
+
+			exclusive_start_key = output.last_evaluated_key;
+			if exclusive_start_key.is_none() {
+				break;
+			}
+		}
+
+		Ok(sessions)
+	}
 }
 
 #[cfg(test)]
@@ -263,6 +406,10 @@ mod test {
 			user_id: String::from("uid"),
 			valid: false,
 			ttl: 0,
+			device_id: None,
+			platform: None,
+			country: None,
+			language: None,
 		};
 
 		let map: DynamoHashMap = s.clone().try_into().unwrap();
@@ -271,6 +418,26 @@ mod test {
 
 		assert_eq!(s, s2);
 	}
+
+	#[test]
+	fn test_serialize_device() {
+		let s = DynamoSession {
+			id: String::from("sid"),
+			user_id: String::from("uid"),
+			valid: true,
+			ttl: 0,
+			device_id: Some(String::from("device")),
+			platform: Some(String::from("ios")),
+			country: Some(String::from("DE")),
+			language: Some(String::from("de")),
+		};
+
+		let map: DynamoHashMap = s.clone().try_into().unwrap();
+
+		let s2: DynamoSession = map.try_into().unwrap();
+
+		assert_eq!(s, s2);
+	}
 }
 
 #[cfg(test)]
@@ -373,6 +540,40 @@ mod test_ddb {
 		assert!(res.is_some());
 	}
 
+	#[tokio::test]
+	async fn test_session_list_for_user() {
+		let (db, _) = create_test_ddb_session().await;
+
+		let mock = mock_ddb_request_ok(
+			"Query",
+			object! {
+				Items: [
+					{
+						id: {S: "s1"},
+						user_id: {S: "uid"},
+						valid: {S: "true"},
+						ttl: {N: "10000000000"},
+					},
+					{
+						id: {S: "s2"},
+						user_id: {S: "uid"},
+						valid: {S: "true"},
+						ttl: {N: "0"},
+					}
+				]
+			},
+		)
+		.expect(1);
+
+		let sessions = db.list_for_user("uid").await.unwrap();
+
+		mock.assert();
+
+		assert_eq!(sessions.len(), 1);
+		assert_eq!(sessions[0].0, "s1");
+		assert!(sessions[0].1.valid);
+	}
+
 	async fn create_test_ddb_session(
 	) -> (DynamoSessionDB, mockito::Mock) {
 		tracing_subscriber::fmt().try_init().ok();
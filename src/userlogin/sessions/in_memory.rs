@@ -30,4 +30,18 @@ impl SessionDB for InMemorySessionDB {
 
 		Ok(key)
 	}
+
+	async fn list_for_user(
+		&self,
+		user_id: &str,
+	) -> Result<Vec<(String, Session)>> {
+		Ok(self
+			.db
+			.lock()
+			.await
+			.iter()
+			.filter(|(_, session)| session.user_id == user_id)
+			.map(|(key, session)| (key.clone(), session.clone()))
+			.collect())
+	}
 }
@@ -1,16 +1,28 @@
 mod dynamodb;
 mod in_memory;
+#[cfg(feature = "redis")]
+mod redis;
 
+#[cfg(feature = "redis")]
+pub use self::redis::RedisSessionDB;
 pub use dynamodb::DynamoSessionDB;
 pub use in_memory::InMemorySessionDB;
 
 use crate::error::Result;
 use async_trait::async_trait;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Session {
 	pub user_id: String,
 	pub valid: bool,
+	/// client provided id of the device the session was created on
+	pub device_id: Option<String>,
+	/// client provided platform name (e.g. `ios`, `android`)
+	pub platform: Option<String>,
+	/// users country at session creation, see [`Session::with_locale`]
+	pub country: Option<String>,
+	/// users language at session creation
+	pub language: Option<String>,
 }
 
 impl Session {
@@ -19,10 +31,37 @@ impl Session {
 		Self {
 			user_id: user_id.to_string(),
 			valid: true,
+			..Self::default()
 		}
 	}
 
-	fn new_key() -> String {
+	#[must_use]
+	pub fn with_device(
+		mut self,
+		device_id: Option<String>,
+		platform: Option<String>,
+	) -> Self {
+		self.device_id = device_id;
+		self.platform = platform;
+		self
+	}
+
+	/// caches user info on the session so it can be resolved
+	/// without a `UserDB` lookup, e.g. by `session_user_filter`
+	#[must_use]
+	pub fn with_locale(
+		mut self,
+		country: Option<String>,
+		language: Option<String>,
+	) -> Self {
+		self.country = country;
+		self.language = language;
+		self
+	}
+
+	/// generates a new random session id
+	#[must_use]
+	pub fn new_key() -> String {
 		uuid::Uuid::new_v4().to_string()
 	}
 }
@@ -32,4 +71,31 @@ pub trait SessionDB: Send + Sync {
 	async fn create(&self, session: Session) -> Result<String>;
 	async fn invalidate(&self, key: &str) -> Option<()>;
 	async fn get(&self, key: &str) -> Option<Session>;
+
+	/// like `get` but without refreshing the session timeout,
+	/// for lookups that are not done on behalf of the session owner
+	async fn peek(&self, key: &str) -> Option<Session> {
+		self.get(key).await
+	}
+
+	/// returns all not timed out sessions (valid or not) of a user as `(key, session)` pairs
+	async fn list_for_user(
+		&self,
+		user_id: &str,
+	) -> Result<Vec<(String, Session)>>;
+
+	/// invalidates every valid session of a user, returns the number of invalidated sessions
+	async fn invalidate_all_for_user(
+		&self,
+		user_id: &str,
+	) -> Result<usize> {
+		let mut count = 0;
+		for (key, session) in self.list_for_user(user_id).await? {
+			if session.valid && self.invalidate(&key).await.is_some()
+			{
+				count += 1;
+			}
+		}
+		Ok(count)
+	}
 }
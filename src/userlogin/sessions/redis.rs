@@ -0,0 +1,268 @@
+use super::{Session, SessionDB};
+use crate::error::Result;
+use async_trait::async_trait;
+use deadpool_redis::{redis, Pool};
+use std::collections::HashMap;
+use tracing::instrument;
+
+/// session lifetime in seconds, refreshed on every `get`,
+/// the users session set gets the same ttl so it outlives all its sessions
+const SESSION_TTL: usize = 5 * 60;
+
+/// marks a session invalid only if it still exists
+const INVALIDATE_SCRIPT: &str = r"
+if redis.call('EXISTS', KEYS[1]) == 1 then
+	redis.call('HSET', KEYS[1], 'valid', 'false')
+	return 1
+end
+return 0
+";
+
+#[derive(Clone)]
+pub struct RedisSessionDB {
+	pool: Pool,
+	prefix: String,
+}
+
+impl RedisSessionDB {
+	/// create new `RedisSessionDB` instance reusing an existing connection pool,
+	/// all session keys are stored as `{prefix}:{session_id}` and the
+	/// session ids of each user in a set at `{prefix}:user:{user_id}`
+	#[must_use]
+	pub fn new(prefix: &str, pool: Pool) -> Self {
+		Self {
+			pool,
+			prefix: prefix.to_string(),
+		}
+	}
+
+	fn key(&self, session: &str) -> String {
+		format!("{}:{}", self.prefix, session)
+	}
+
+	fn user_key(&self, user_id: &str) -> String {
+		format!("{}:user:{}", self.prefix, user_id)
+	}
+}
+
+fn session_from_fields(
+	fields: &HashMap<String, String>,
+) -> Option<Session> {
+	Some(Session {
+		user_id: fields.get("user_id")?.clone(),
+		valid: fields.get("valid")?.parse::<bool>().ok()?,
+		device_id: fields.get("device_id").cloned(),
+		platform: fields.get("platform").cloned(),
+		country: fields.get("country").cloned(),
+		language: fields.get("language").cloned(),
+	})
+}
+
+#[async_trait]
+impl SessionDB for RedisSessionDB {
+	#[instrument(skip(self), err)]
+	async fn create(&self, session: Session) -> Result<String> {
+		tracing::trace!("SessionDB::create");
+
+		let id = Session::new_key();
+		let key = self.key(&id);
+		let user_key = self.user_key(&session.user_id);
+
+		let mut fields = vec![
+			("user_id", session.user_id),
+			("valid", session.valid.to_string()),
+		];
+		if let Some(device_id) = session.device_id {
+			fields.push(("device_id", device_id));
+		}
+		if let Some(platform) = session.platform {
+			fields.push(("platform", platform));
+		}
+		if let Some(country) = session.country {
+			fields.push(("country", country));
+		}
+		if let Some(language) = session.language {
+			fields.push(("language", language));
+		}
+
+		let mut con = self.pool.get().await?;
+
+		redis::pipe()
+			.atomic()
+			.sadd(&user_key, &id)
+			.ignore()
+			.expire(&user_key, SESSION_TTL)
+			.ignore()
+			.hset_multiple(&key, &fields)
+			.ignore()
+			.expire(&key, SESSION_TTL)
+			.ignore()
+			.query_async::<_, ()>(&mut con)
+			.await?;
+
+		Ok(id)
+	}
+
+	#[instrument(skip(self))]
+	async fn invalidate(&self, key: &str) -> Option<()> {
+		tracing::debug!("SessionDB::invalidate");
+
+		let mut con = self
+			.pool
+			.get()
+			.await
+			.map_err(|e| tracing::error!("redis pool error: {}", e))
+			.ok()?;
+
+		let updated: i32 = redis::cmd("EVAL")
+			.arg(INVALIDATE_SCRIPT)
+			.arg(1)
+			.arg(self.key(key))
+			.query_async(&mut con)
+			.await
+			.map_err(|e| {
+				tracing::error!("error invalidating session: {}", e);
+			})
+			.ok()?;
+
+		(updated == 1).then_some(())
+	}
+
+	#[instrument(skip(self))]
+	async fn get(&self, key: &str) -> Option<Session> {
+		tracing::trace!("SessionDB::get");
+
+		let key = self.key(key);
+
+		let mut con = self
+			.pool
+			.get()
+			.await
+			.map_err(|e| tracing::error!("redis pool error: {}", e))
+			.ok()?;
+
+		let (fields,): (HashMap<String, String>,) = redis::pipe()
+			.atomic()
+			.hgetall(&key)
+			.expire(&key, SESSION_TTL)
+			.ignore()
+			.query_async(&mut con)
+			.await
+			.map_err(|e| tracing::error!("get error: {}", e))
+			.ok()?;
+
+		let session = session_from_fields(&fields)?;
+
+		// keep the session listed for `list_for_user` as long as it lives
+		if let Err(e) = redis::cmd("EXPIRE")
+			.arg(self.user_key(&session.user_id))
+			.arg(SESSION_TTL)
+			.query_async::<_, ()>(&mut con)
+			.await
+		{
+			tracing::error!("user set expire error: {e}");
+		}
+
+		Some(session)
+	}
+
+	#[instrument(skip(self))]
+	async fn peek(&self, key: &str) -> Option<Session> {
+		tracing::trace!("SessionDB::peek");
+
+		let mut con = self
+			.pool
+			.get()
+			.await
+			.map_err(|e| tracing::error!("redis pool error: {e}"))
+			.ok()?;
+
+		let fields: HashMap<String, String> = redis::cmd("HGETALL")
+			.arg(self.key(key))
+			.query_async(&mut con)
+			.await
+			.map_err(|e| tracing::error!("peek error: {e}"))
+			.ok()?;
+
+		session_from_fields(&fields)
+	}
+
+	/// session ids whose keys expired are lazily removed from the users set here
+	#[instrument(skip(self), err)]
+	async fn list_for_user(
+		&self,
+		user_id: &str,
+	) -> Result<Vec<(String, Session)>> {
+		tracing::trace!("SessionDB::list_for_user");
+
+		let user_key = self.user_key(user_id);
+
+		let mut con = self.pool.get().await?;
+
+		let ids: Vec<String> = redis::cmd("SMEMBERS")
+			.arg(&user_key)
+			.query_async(&mut con)
+			.await?;
+
+		if ids.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		let mut pipe = redis::pipe();
+		for id in &ids {
+			pipe.hgetall(self.key(id));
+		}
+		let fields: Vec<HashMap<String, String>> =
+			pipe.query_async(&mut con).await?;
+
+		let mut sessions = Vec::new();
+		let mut expired = Vec::new();
+		for (id, fields) in ids.into_iter().zip(fields) {
+			match session_from_fields(&fields) {
+				Some(session) => sessions.push((id, session)),
+				None => expired.push(id),
+			}
+		}
+
+		if !expired.is_empty() {
+			redis::cmd("SREM")
+				.arg(&user_key)
+				.arg(expired)
+				.query_async::<_, ()>(&mut con)
+				.await?;
+		}
+
+		Ok(sessions)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_deserialize() {
+		let mut fields = HashMap::new();
+		fields.insert("user_id".to_string(), "uid".to_string());
+		fields.insert("valid".to_string(), "false".to_string());
+
+		let session = session_from_fields(&fields).unwrap();
+
+		assert_eq!(session.user_id, "uid");
+		assert!(!session.valid);
+		assert!(session.device_id.is_none());
+
+		fields.insert("device_id".to_string(), "device".to_string());
+		fields.insert("platform".to_string(), "ios".to_string());
+
+		let session = session_from_fields(&fields).unwrap();
+
+		assert_eq!(session.device_id, Some("device".to_string()));
+		assert_eq!(session.platform, Some("ios".to_string()));
+	}
+
+	#[test]
+	fn test_deserialize_missing() {
+		assert!(session_from_fields(&HashMap::new()).is_none());
+	}
+}
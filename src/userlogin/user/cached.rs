@@ -0,0 +1,362 @@
+use super::{User, UserDB};
+use crate::error::Result;
+use async_trait::async_trait;
+use lru::LruCache;
+use std::{
+	num::NonZeroUsize,
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
+use tracing::instrument;
+
+#[cfg(feature = "redis")]
+use deadpool_redis::{redis, Pool};
+
+/// stores `ARGV[1]` unless a user of the same or a newer version
+/// (`ARGV[2]`) is cached already, so a read-through of an outdated user
+/// racing a save can't replace the saved one
+#[cfg(feature = "redis")]
+const SET_IF_NEWER_SCRIPT: &str = r"
+local cached = redis.call('GET', KEYS[1])
+if cached and cjson.decode(cached)['version'] >= tonumber(ARGV[2]) then
+	return 0
+end
+redis.call('SET', KEYS[1], ARGV[1], 'EX', ARGV[3])
+return 1
+";
+
+/// read-through cache in front of any [`UserDB`]
+///
+/// the in memory tier is only invalidated by `save_user`/`delete_user`
+/// of the same instance, other instances serve a user up to `ttl` old,
+/// so it is meant for single instance deployments. With
+/// [`Self::with_redis`] the in memory tier is bypassed and all instances
+/// share the redis tier, which every instance writes saved users through to.
+pub struct CachedUserDB {
+	inner: Arc<dyn UserDB>,
+	cache: Mutex<LruCache<String, (Instant, User)>>,
+	ttl: Duration,
+	#[cfg(feature = "redis")]
+	redis: Option<RedisTier>,
+}
+
+impl CachedUserDB {
+	/// caches up to `capacity` users in memory, evicting the least recently used
+	#[must_use]
+	pub fn new(
+		inner: Arc<dyn UserDB>,
+		capacity: usize,
+		ttl: Duration,
+	) -> Self {
+		let capacity =
+			NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+
+		Self {
+			inner,
+			cache: Mutex::new(LruCache::new(capacity)),
+			ttl,
+			#[cfg(feature = "redis")]
+			redis: None,
+		}
+	}
+
+	/// replaces the in memory tier by a shared one storing users as json
+	/// at `{prefix}:{user_id}` for `ttl`
+	#[cfg(feature = "redis")]
+	#[must_use]
+	pub fn with_redis(
+		mut self,
+		prefix: &str,
+		pool: Pool,
+		ttl: Duration,
+	) -> Self {
+		self.redis = Some(RedisTier {
+			pool,
+			prefix: prefix.to_string(),
+			ttl: usize::try_from(ttl.as_secs())
+				.unwrap_or(usize::MAX)
+				.max(1),
+		});
+		self
+	}
+
+	/// in memory entries could be stale if other instances share the redis tier
+	#[cfg(feature = "redis")]
+	const fn local(&self) -> bool {
+		self.redis.is_none()
+	}
+
+	#[cfg(not(feature = "redis"))]
+	#[allow(clippy::unused_self)]
+	const fn local(&self) -> bool {
+		true
+	}
+
+	fn cached(&self, key: &str) -> Option<User> {
+		if !self.local() {
+			return None;
+		}
+
+		let mut cache = self.cache.lock().ok()?;
+
+		match cache.get(key) {
+			Some((at, user)) if at.elapsed() < self.ttl => {
+				Some(user.clone())
+			}
+			Some(_) => {
+				cache.pop(key);
+				None
+			}
+			None => None,
+		}
+	}
+
+	fn cache(&self, user: &User) {
+		if !self.local() {
+			return;
+		}
+
+		if let Ok(mut cache) = self.cache.lock() {
+			cache
+				.put(user.id.clone(), (Instant::now(), user.clone()));
+		}
+	}
+
+	fn invalidate(&self, key: &str) {
+		if let Ok(mut cache) = self.cache.lock() {
+			cache.pop(key);
+		}
+	}
+}
+
+#[async_trait]
+impl UserDB for CachedUserDB {
+	#[instrument(skip(self))]
+	async fn get_user(&self, key: &str) -> Option<User> {
+		if let Some(user) = self.cached(key) {
+			tracing::trace!("user cache hit");
+			return Some(user);
+		}
+
+		#[cfg(feature = "redis")]
+		if let Some(user) = match self.redis.as_ref() {
+			Some(redis) => redis.get(key).await,
+			None => None,
+		} {
+			tracing::trace!("user redis cache hit");
+			return Some(user);
+		}
+
+		let user = self.inner.get_user(key).await?;
+
+		self.cache(&user);
+
+		#[cfg(feature = "redis")]
+		if let Some(redis) = self.redis.as_ref() {
+			redis.set(&user).await;
+		}
+
+		Some(user)
+	}
+
+	/// the in memory entry is dropped even if saving fails (e.g. version mismatch)
+	/// as it is likely outdated then
+	///
+	/// the redis tier gets the saved user written through instead,
+	/// after a failed save it keeps the newer user saved meanwhile
+	async fn save_user(&self, u: &User) -> Result<()> {
+		let res = self.inner.save_user(u).await;
+
+		self.invalidate(&u.id);
+
+		#[cfg(feature = "redis")]
+		if let (Ok(()), Some(redis)) = (&res, self.redis.as_ref()) {
+			redis.set(u).await;
+		}
+
+		res
+	}
+
+	/// a read-through racing the delete may cache the soft deleted user
+	/// in the redis tier again until its ttl is over
+	async fn delete_user(
+		&self,
+		key: &str,
+		timestamp: i64,
+	) -> Result<bool> {
+		let res = self.inner.delete_user(key, timestamp).await;
+
+		self.invalidate(key);
+
+		#[cfg(feature = "redis")]
+		if let Some(redis) = self.redis.as_ref() {
+			redis.del(key).await;
+		}
+
+		res
+	}
+
+	async fn list_deleted_before(
+		&self,
+		timestamp: i64,
+	) -> Result<Vec<String>> {
+		self.inner.list_deleted_before(timestamp).await
+	}
+}
+
+/// redis errors are only logged, the cache falls back to the inner db
+#[cfg(feature = "redis")]
+struct RedisTier {
+	pool: Pool,
+	prefix: String,
+	ttl: usize,
+}
+
+#[cfg(feature = "redis")]
+impl RedisTier {
+	fn key(&self, user_id: &str) -> String {
+		format!("{}:{user_id}", self.prefix)
+	}
+
+	async fn get(&self, user_id: &str) -> Option<User> {
+		let mut con = self
+			.pool
+			.get()
+			.await
+			.map_err(|e| tracing::error!("redis pool error: {e}"))
+			.ok()?;
+
+		let json: Option<String> = redis::cmd("GET")
+			.arg(self.key(user_id))
+			.query_async(&mut con)
+			.await
+			.map_err(|e| tracing::error!("user cache get error: {e}"))
+			.ok()?;
+
+		serde_json::from_str(&json?)
+			.map_err(|e| {
+				tracing::error!("user cache json error: {e}");
+			})
+			.ok()
+	}
+
+	async fn set(&self, user: &User) {
+		let json = match serde_json::to_string(user) {
+			Ok(json) => json,
+			Err(e) => {
+				tracing::error!("user cache json error: {e}");
+				return;
+			}
+		};
+
+		let res = match self.pool.get().await {
+			Ok(mut con) => redis::cmd("EVAL")
+				.arg(SET_IF_NEWER_SCRIPT)
+				.arg(1)
+				.arg(self.key(&user.id))
+				.arg(json)
+				.arg(user.version)
+				.arg(self.ttl)
+				.query_async::<_, ()>(&mut con)
+				.await
+				.map_err(|e| e.to_string()),
+			Err(e) => Err(e.to_string()),
+		};
+
+		if let Err(e) = res {
+			tracing::error!("user cache set error: {e}");
+		}
+	}
+
+	async fn del(&self, user_id: &str) {
+		let res = match self.pool.get().await {
+			Ok(mut con) => redis::cmd("DEL")
+				.arg(self.key(user_id))
+				.query_async::<_, ()>(&mut con)
+				.await
+				.map_err(|e| e.to_string()),
+			Err(e) => Err(e.to_string()),
+		};
+
+		if let Err(e) = res {
+			tracing::error!("user cache del error: {e}");
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::userlogin::user::in_memory::InMemoryUserDB;
+
+	fn cached(
+		capacity: usize,
+		ttl: Duration,
+	) -> (Arc<InMemoryUserDB>, CachedUserDB) {
+		let inner = Arc::new(InMemoryUserDB::default());
+		let db = CachedUserDB::new(inner.clone(), capacity, ttl);
+		(inner, db)
+	}
+
+	#[tokio::test]
+	async fn test_read_through() {
+		let (inner, db) = cached(10, Duration::from_secs(60));
+
+		let mut user = User::new(None, None);
+		inner.save_user(&user).await.unwrap();
+
+		assert_eq!(db.get_user(&user.id).await.unwrap(), user);
+
+		// changes bypassing the cache are not seen until invalidated
+		user.language = Some("de".into());
+		inner.save_user(&user).await.unwrap();
+		assert_eq!(
+			db.get_user(&user.id).await.unwrap().language,
+			None
+		);
+
+		user.version += 1;
+		db.save_user(&user).await.unwrap();
+		assert_eq!(db.get_user(&user.id).await.unwrap(), user);
+
+		user.version += 1;
+		user.deleted_at = Some(100);
+		db.save_user(&user).await.unwrap();
+		db.get_user(&user.id).await.unwrap();
+
+		assert!(db.delete_user(&user.id, 101).await.unwrap());
+		assert!(db.get_user(&user.id).await.is_none());
+	}
+
+	#[tokio::test]
+	async fn test_ttl() {
+		let (inner, db) = cached(10, Duration::ZERO);
+
+		let mut user = User::new(None, None);
+		inner.save_user(&user).await.unwrap();
+		db.get_user(&user.id).await.unwrap();
+
+		user.language = Some("de".into());
+		inner.save_user(&user).await.unwrap();
+
+		assert_eq!(db.get_user(&user.id).await.unwrap(), user);
+	}
+
+	#[tokio::test]
+	async fn test_lru_eviction() {
+		let (inner, db) = cached(1, Duration::from_secs(60));
+
+		let mut first = User::new(None, None);
+		let second = User::new(None, None);
+		inner.save_user(&first).await.unwrap();
+		inner.save_user(&second).await.unwrap();
+
+		db.get_user(&first.id).await.unwrap();
+		db.get_user(&second.id).await.unwrap();
+
+		first.language = Some("de".into());
+		inner.save_user(&first).await.unwrap();
+
+		assert_eq!(db.get_user(&first.id).await.unwrap(), first);
+	}
+}
@@ -9,9 +9,10 @@ use crate::{
 	error::{Error, Result},
 };
 use async_trait::async_trait;
+use rusoto_core::RusotoError;
 use rusoto_dynamodb::{
-	AttributeValue, DynamoDb, DynamoDbClient, GetItemInput,
-	PutItemInput,
+	AttributeValue, DeleteItemError, DeleteItemInput, DynamoDb,
+	DynamoDbClient, GetItemInput, PutItemInput, ScanInput,
 };
 
 #[derive(Clone)]
@@ -133,6 +134,15 @@ impl From<User> for DynamoHashMap {
 				},
 			);
 		};
+		if let Some(deleted_at) = v.deleted_at {
+			map.insert(
+				"deleted_at".to_string(),
+				AttributeValue {
+					n: Some(deleted_at.to_string()),
+					..AttributeValue::default()
+				},
+			);
+		}
 
 		map
 	}
@@ -166,6 +176,10 @@ impl TryFrom<HashMap<String, AttributeValue>> for User {
 			language: attributes
 				.get("language")
 				.and_then(|attr| attr.s.clone()),
+			deleted_at: attributes
+				.get("deleted_at")
+				.and_then(|attr| attr.n.as_ref())
+				.and_then(|n| n.parse::<i64>().ok()),
 		})
 	}
 }
@@ -181,4 +195,91 @@ impl UserDB for DynamoUserDB {
 	async fn save_user(&self, u: &User) -> Result<()> {
 		Ok(self.save(u.clone()).await?)
 	}
+
+	async fn delete_user(
+		&self,
+		key: &str,
+		timestamp: i64,
+	) -> Result<bool> {
+		let mut value_map = HashMap::new();
+		value_map.insert(
+			":ts".to_string(),
+			AttributeValue {
+				n: Some(timestamp.to_string()),
+				..AttributeValue::default()
+			},
+		);
+
+		let res = self
+			.db
+			.delete_item(DeleteItemInput {
+				table_name: self.table.clone(),
+				key: db_key("id", key),
+				condition_expression: Some(String::from(
+					"deleted_at < :ts",
+				)),
+				expression_attribute_values: Some(value_map),
+				..DeleteItemInput::default()
+			})
+			.await;
+
+		match res {
+			Ok(_) => Ok(true),
+			Err(RusotoError::Service(
+				DeleteItemError::ConditionalCheckFailed(_),
+			)) => Ok(false),
+			Err(e) => Err(e.into()),
+		}
+	}
+
+	/// scans the whole table, meant for infrequent background purges
+	async fn list_deleted_before(
+		&self,
+		timestamp: i64,
+	) -> Result<Vec<String>> {
+		let mut value_map = HashMap::new();
+		value_map.insert(
+			":ts".to_string(),
+			AttributeValue {
+				n: Some(timestamp.to_string()),
+				..AttributeValue::default()
+			},
+		);
+
+		let mut ids = Vec::new();
+		let mut exclusive_start_key = None;
+
+		loop {
+			let output = self
+				.db
+				.scan(ScanInput {
+					table_name: self.table.clone(),
+					filter_expression: Some(String::from(
+						"deleted_at < :ts",
+					)),
+					projection_expression: Some(String::from("id")),
+					expression_attribute_values: Some(
+						value_map.clone(),
+					),
+					exclusive_start_key,
+					..ScanInput::default()
+				})
+				.await?;
+
+			ids.extend(
+				output
+					.items
+					.unwrap_or_default()
+					.into_iter()
+					.filter_map(|mut item| item.remove("id")?.s),
+			);
+
+			exclusive_start_key = output.last_evaluated_key;
+			if exclusive_start_key.is_none() {
+				break;
+			}
+		}
+
+		Ok(ids)
+	}
 }
@@ -22,4 +22,37 @@ impl UserDB for InMemoryUserDB {
 
 		Ok(())
 	}
+
+	async fn delete_user(
+		&self,
+		key: &str,
+		timestamp: i64,
+	) -> Result<bool> {
+		let mut db = self.db.lock().await;
+
+		let deleted = db
+			.get(key)
+			.and_then(|u| u.deleted_at)
+			.is_some_and(|at| at < timestamp);
+		if deleted {
+			db.remove(key);
+		}
+		drop(db);
+
+		Ok(deleted)
+	}
+
+	async fn list_deleted_before(
+		&self,
+		timestamp: i64,
+	) -> Result<Vec<String>> {
+		Ok(self
+			.db
+			.lock()
+			.await
+			.values()
+			.filter(|u| u.deleted_at.is_some_and(|at| at < timestamp))
+			.map(|u| u.id.clone())
+			.collect())
+	}
 }
@@ -1,3 +1,4 @@
+pub mod cached;
 pub mod dynamodb;
 pub mod in_memory;
 
@@ -16,6 +17,8 @@ pub struct User {
 	pub session: Option<String>,
 	pub country: Option<String>,
 	pub language: Option<String>,
+	/// unix timestamp (seconds) of the soft delete, purged after a grace period
+	pub deleted_at: Option<i64>,
 }
 
 impl User {
@@ -32,10 +35,28 @@ impl User {
 			..Self::default()
 		}
 	}
+
+	#[must_use]
+	pub const fn is_deleted(&self) -> bool {
+		self.deleted_at.is_some()
+	}
 }
 
 #[async_trait]
 pub trait UserDB: Send + Sync {
 	async fn get_user(&self, key: &str) -> Option<User>;
 	async fn save_user(&self, u: &User) -> Result<()>;
+	/// permanently removes the user if it is (still) soft deleted before
+	/// `timestamp` (unix seconds), see [`User::deleted_at`],
+	/// returns `false` if it was not
+	async fn delete_user(
+		&self,
+		key: &str,
+		timestamp: i64,
+	) -> Result<bool>;
+	/// ids of users soft deleted before `timestamp` (unix seconds)
+	async fn list_deleted_before(
+		&self,
+		timestamp: i64,
+	) -> Result<Vec<String>>;
 }
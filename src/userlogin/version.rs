@@ -0,0 +1,109 @@
+use super::MIN_CLIENT_VERSION;
+use crate::schema;
+use std::collections::HashMap;
+
+/// client version policy for login/register
+#[derive(Debug, Clone)]
+pub struct VersionConfig {
+	/// clients below are blocked (`isOutdated`) until they update
+	pub min_version: u32,
+	/// clients below (but not blocked) are softly asked to update
+	pub latest_version: Option<u32>,
+	/// store page per platform name as sent by the client (e.g. `ios`, `android`)
+	pub store_urls: HashMap<String, String>,
+}
+
+impl Default for VersionConfig {
+	fn default() -> Self {
+		Self {
+			min_version: MIN_CLIENT_VERSION,
+			latest_version: None,
+			store_urls: HashMap::new(),
+		}
+	}
+}
+
+impl VersionConfig {
+	#[must_use]
+	pub const fn is_supported(&self, client_version: u32) -> bool {
+		client_version >= self.min_version
+	}
+
+	/// returns the update info to send to a client of `client_version`
+	/// on `platform`, `None` if it is up to date
+	#[must_use]
+	pub fn update_info(
+		&self,
+		client_version: u32,
+		platform: &str,
+	) -> Option<schema::UpdateInfo> {
+		let force_update = !self.is_supported(client_version);
+		let outdated = self
+			.latest_version
+			.is_some_and(|latest| client_version < latest);
+
+		(force_update || outdated).then(|| schema::UpdateInfo {
+			minVersion: self.min_version,
+			latestVersion: self
+				.latest_version
+				.unwrap_or(self.min_version),
+			forceUpdate: force_update,
+			storeUrl: self
+				.store_urls
+				.get(platform)
+				.cloned()
+				.unwrap_or_default(),
+			..schema::UpdateInfo::default()
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn config() -> VersionConfig {
+		let mut store_urls = HashMap::new();
+		store_urls.insert(
+			"ios".to_string(),
+			"https://apps.apple.com/app/id1".to_string(),
+		);
+
+		VersionConfig {
+			min_version: 10,
+			latest_version: Some(20),
+			store_urls,
+		}
+	}
+
+	#[test]
+	fn test_update_info() {
+		let config = config();
+
+		let blocked = config.update_info(5, "ios").unwrap();
+		assert!(blocked.forceUpdate);
+		assert_eq!(blocked.minVersion, 10);
+		assert_eq!(blocked.latestVersion, 20);
+		assert_eq!(
+			blocked.storeUrl,
+			"https://apps.apple.com/app/id1"
+		);
+
+		let soft = config.update_info(10, "android").unwrap();
+		assert!(!soft.forceUpdate);
+		assert_eq!(soft.storeUrl, "");
+
+		assert!(config.update_info(20, "ios").is_none());
+	}
+
+	#[test]
+	fn test_update_info_no_latest() {
+		let config = VersionConfig {
+			latest_version: None,
+			..config()
+		};
+
+		assert!(config.update_info(5, "ios").unwrap().forceUpdate);
+		assert!(config.update_info(10, "ios").is_none());
+	}
+}
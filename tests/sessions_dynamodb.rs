@@ -57,6 +57,55 @@ async fn test_session_valid() {
 	assert_eq!(valid_res.unwrap().user_id, "uid");
 }
 
+#[tokio::test]
+async fn test_session_peek() {
+	let (db, _) = create_test_ddb_session().await;
+
+	let mock = mock_ddb_request_ok(
+		"GetItem",
+		object! {
+			Item: {
+				id: {S: "session"},
+				user_id: {S: "uid"},
+				valid: {S: "true"},
+				//TODO: unhardcode timestamp
+				ttl: {N: "10000000000"},
+			}
+		},
+	)
+	.expect(1);
+
+	let session = db.peek("sessionid").await;
+
+	mock.assert();
+
+	assert_eq!(session.unwrap().user_id, "uid");
+}
+
+#[tokio::test]
+async fn test_session_peek_expired() {
+	let (db, _) = create_test_ddb_session().await;
+
+	let mock = mock_ddb_request_ok(
+		"GetItem",
+		object! {
+			Item: {
+				id: {S: "session"},
+				user_id: {S: "uid"},
+				valid: {S: "true"},
+				ttl: {N: "1"},
+			}
+		},
+	)
+	.expect(1);
+
+	let session = db.peek("sessionid").await;
+
+	mock.assert();
+
+	assert!(session.is_none());
+}
+
 #[tokio::test]
 async fn test_session_invalid() {
 	let (db, _) = create_test_ddb_session().await;